@@ -0,0 +1,3221 @@
+//! Typed requests and responses of the CKAN actions, generated from `docs/lib/openapi.yml`
+//! by `cargo xtask openapi`. Do not edit by hand. Send a request with
+//! [`CKAN::send`](crate::CKAN::send).
+
+use crate::{
+    Action,
+    params::{
+        GroupSort, HarvestFrequency, HarvestJobStatus, ObjectType, OrderBy, OwnerType, PackageSort,
+        Permission, Role, State, UpsertMethod, ViewType,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The actions that only read data, marked with `x-read-only` in the spec.
+pub const READ_ACTIONS: &[&str] = &[
+    "package_list",
+    "current_package_list_with_resources",
+    "member_list",
+    "package_collaborator_list",
+    "package_collaborator_list_for_user",
+    "group_list",
+    "organization_list",
+    "group_list_authz",
+    "organization_list_for_user",
+    "license_list",
+    "tag_list",
+    "user_list",
+    "package_relationships_list",
+    "package_show",
+    "resource_show",
+    "resource_view_show",
+    "resource_view_list",
+    "group_show",
+    "organization_show",
+    "group_package_show",
+    "tag_show",
+    "user_show",
+    "package_autocomplete",
+    "format_autocomplete",
+    "user_autocomplete",
+    "group_autocomplete",
+    "organization_autocomplete",
+    "package_search",
+    "resource_search",
+    "tag_search",
+    "tag_autocomplete",
+    "task_status_show",
+    "term_translation_show",
+    "get_site_user",
+    "status_show",
+    "vocabulary_list",
+    "vocabulary_show",
+    "user_follower_count",
+    "dataset_follower_count",
+    "group_follower_count",
+    "organization_follower_count",
+    "user_follower_list",
+    "dataset_follower_list",
+    "group_follower_list",
+    "organization_follower_list",
+    "am_following_user",
+    "am_following_dataset",
+    "am_following_group",
+    "followee_count",
+    "user_followee_count",
+    "dataset_followee_count",
+    "group_followee_count",
+    "organization_followee_count",
+    "followee_list",
+    "user_followee_list",
+    "dataset_followee_list",
+    "group_followee_list",
+    "organization_followee_list",
+    "member_roles_list",
+    "help_show",
+    "config_option_show",
+    "config_option_list",
+    "job_list",
+    "job_show",
+    "api_token_list",
+    "site_read",
+    "activity_show",
+    "activity_data_show",
+    "activity_diff",
+    "package_activity_list",
+    "group_activity_list",
+    "organization_activity_list",
+    "user_activity_list",
+    "recently_changed_packages_activity_list",
+    "dashboard_activity_list",
+    "dashboard_new_activities_count",
+    "scheming_dataset_schema_show",
+    "file_show",
+    "harvest_source_show",
+    "harvest_source_list",
+    "harvest_job_list",
+    "harvest_job_show",
+    "harvest_object_show",
+    "harvest_log_list",
+    "xloader_status",
+    "datapusher_status",
+    "datastore_info",
+    "datastore_search",
+    "datastore_search_sql",
+];
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+}
+
+impl Action for PackageList {
+    const NAME: &'static str = "package_list";
+    type Response = Vec<String>;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.current_package_list_with_resources
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct CurrentPackageListWithResources {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+impl Action for CurrentPackageListWithResources {
+    const NAME: &'static str = "current_package_list_with_resources";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.member_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct MemberList {
+    pub id: String,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<ObjectType>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Role>,
+}
+
+impl Action for MemberList {
+    const NAME: &'static str = "member_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_collaborator_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCollaboratorList {
+    pub id: String,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Role>,
+}
+
+impl Action for PackageCollaboratorList {
+    const NAME: &'static str = "package_collaborator_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_collaborator_list_for_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCollaboratorListForUser {
+    pub id: String,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Role>,
+}
+
+impl Action for PackageCollaboratorListForUser {
+    const NAME: &'static str = "package_collaborator_list_for_user";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupList {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<GroupSort>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fields: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_dataset_count: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_extras: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_groups: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+}
+
+impl Action for GroupList {
+    const NAME: &'static str = "group_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationList {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<GroupSort>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizations: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fields: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_dataset_count: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_extras: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_groups: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+}
+
+impl Action for OrganizationList {
+    const NAME: &'static str = "organization_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_list_authz
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupListAuthz {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub am_member: Option<bool>,
+}
+
+impl Action for GroupListAuthz {
+    const NAME: &'static str = "group_list_authz";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_list_for_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationListForUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<Permission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_dataset_count: Option<bool>,
+}
+
+impl Action for OrganizationListForUser {
+    const NAME: &'static str = "organization_list_for_user";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.license_list
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct LicenseList;
+
+impl Action for LicenseList {
+    const NAME: &'static str = "license_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vocabulary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fields: Option<bool>,
+}
+
+impl Action for TagList {
+    const NAME: &'static str = "tag_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fields: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_site_user: Option<bool>,
+}
+
+impl Action for UserList {
+    const NAME: &'static str = "user_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_relationships_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageRelationshipsList {
+    pub id: String,
+    pub id2: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rel: Option<String>,
+}
+
+impl Action for PackageRelationshipsList {
+    const NAME: &'static str = "package_relationships_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_default_schema: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_plugin_data: Option<bool>,
+}
+
+impl Action for PackageShow {
+    const NAME: &'static str = "package_show";
+    type Response = crate::types::Package;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceShow {
+    pub id: String,
+}
+
+impl Action for ResourceShow {
+    const NAME: &'static str = "resource_show";
+    type Response = crate::types::Resource;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_view_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewShow {
+    pub id: String,
+}
+
+impl Action for ResourceViewShow {
+    const NAME: &'static str = "resource_view_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_view_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewList {
+    pub id: String,
+}
+
+impl Action for ResourceViewList {
+    const NAME: &'static str = "resource_view_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_datasets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_dataset_count: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_extras: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_groups: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_followers: Option<bool>,
+}
+
+impl Action for GroupShow {
+    const NAME: &'static str = "group_show";
+    type Response = crate::types::Group;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_datasets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_dataset_count: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_extras: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_groups: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_tags: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_followers: Option<bool>,
+}
+
+impl Action for OrganizationShow {
+    const NAME: &'static str = "organization_show";
+    type Response = crate::types::Group;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_package_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupPackageShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for GroupPackageShow {
+    const NAME: &'static str = "group_package_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vocabulary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_datasets: Option<bool>,
+}
+
+impl Action for TagShow {
+    const NAME: &'static str = "tag_show";
+    type Response = crate::types::Tag;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_datasets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_num_followers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_password_hash: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_plugin_extras: Option<bool>,
+}
+
+impl Action for UserShow {
+    const NAME: &'static str = "user_show";
+    type Response = crate::types::User;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageAutocomplete {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for PackageAutocomplete {
+    const NAME: &'static str = "package_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.format_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FormatAutocomplete {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for FormatAutocomplete {
+    const NAME: &'static str = "format_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserAutocomplete {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for UserAutocomplete {
+    const NAME: &'static str = "user_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupAutocomplete {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for GroupAutocomplete {
+    const NAME: &'static str = "group_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationAutocomplete {
+    pub q: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for OrganizationAutocomplete {
+    const NAME: &'static str = "organization_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// The result of `package_search`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageSearchResponse {
+    pub count: u32,
+    pub results: Vec<crate::types::Package>,
+    pub facets: Option<serde_json::Value>,
+    pub search_facets: Option<serde_json::Value>,
+    pub sort: Option<String>,
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_search
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageSearch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fq: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fq_list: Option<Vec<String>>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<PackageSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rows: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet: Option<String>,
+    #[serde(rename = "facet.mincount")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_mincount: Option<i32>,
+    #[serde(rename = "facet.limit")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_limit: Option<i32>,
+    #[serde(rename = "facet.field")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_field: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_drafts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_deleted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_default_schema: Option<bool>,
+    #[cfg(feature = "spatial")]
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_bbox: Option<crate::spatial::BBox>,
+    #[cfg(feature = "spatial")]
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ext_prev_extent: Option<crate::spatial::BBox>,
+}
+
+impl Action for PackageSearch {
+    const NAME: &'static str = "package_search";
+    type Response = PackageSearchResponse;
+    const EXTRAS: &'static [&'static str] = &["ext_bbox", "ext_prev_extent"];
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_search
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceSearch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<serde_json::Value>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<OrderBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+impl Action for ResourceSearch {
+    const NAME: &'static str = "resource_search";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_search
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagSearch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vocabulary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+}
+
+impl Action for TagSearch {
+    const NAME: &'static str = "tag_search";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_autocomplete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagAutocomplete {
+    pub query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vocabulary_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+}
+
+impl Action for TagAutocomplete {
+    const NAME: &'static str = "tag_autocomplete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.task_status_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TaskStatusShow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl Action for TaskStatusShow {
+    const NAME: &'static str = "task_status_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.term_translation_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TermTranslationShow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lang_codes: Option<Vec<String>>,
+}
+
+impl Action for TermTranslationShow {
+    const NAME: &'static str = "term_translation_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.get_site_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GetSiteUser {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub defer_commit: Option<bool>,
+}
+
+impl Action for GetSiteUser {
+    const NAME: &'static str = "get_site_user";
+    type Response = serde_json::Value;
+}
+
+/// The result of `status_show`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatusShowResponse {
+    pub ckan_version: String,
+    pub site_title: Option<String>,
+    pub site_description: Option<String>,
+    pub site_url: Option<String>,
+    pub extensions: Option<Vec<String>>,
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.status_show
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct StatusShow;
+
+impl Action for StatusShow {
+    const NAME: &'static str = "status_show";
+    type Response = StatusShowResponse;
+    const GET: bool = true;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.vocabulary_list
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct VocabularyList;
+
+impl Action for VocabularyList {
+    const NAME: &'static str = "vocabulary_list";
+    type Response = serde_json::Value;
+    const GET: bool = true;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.vocabulary_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct VocabularyShow {
+    pub id: String,
+}
+
+impl Action for VocabularyShow {
+    const NAME: &'static str = "vocabulary_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_follower_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserFollowerCount {
+    pub id: String,
+}
+
+impl Action for UserFollowerCount {
+    const NAME: &'static str = "user_follower_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_follower_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatasetFollowerCount {
+    pub id: String,
+}
+
+impl Action for DatasetFollowerCount {
+    const NAME: &'static str = "dataset_follower_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_follower_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupFollowerCount {
+    pub id: String,
+}
+
+impl Action for GroupFollowerCount {
+    const NAME: &'static str = "group_follower_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_follower_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationFollowerCount {
+    pub id: String,
+}
+
+impl Action for OrganizationFollowerCount {
+    const NAME: &'static str = "organization_follower_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_follower_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserFollowerList {
+    pub id: String,
+}
+
+impl Action for UserFollowerList {
+    const NAME: &'static str = "user_follower_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_follower_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatasetFollowerList {
+    pub id: String,
+}
+
+impl Action for DatasetFollowerList {
+    const NAME: &'static str = "dataset_follower_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_follower_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupFollowerList {
+    pub id: String,
+}
+
+impl Action for GroupFollowerList {
+    const NAME: &'static str = "group_follower_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_follower_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationFollowerList {
+    pub id: String,
+}
+
+impl Action for OrganizationFollowerList {
+    const NAME: &'static str = "organization_follower_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct AmFollowingUser {
+    pub id: String,
+}
+
+impl Action for AmFollowingUser {
+    const NAME: &'static str = "am_following_user";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_dataset
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct AmFollowingDataset {
+    pub id: String,
+}
+
+impl Action for AmFollowingDataset {
+    const NAME: &'static str = "am_following_dataset";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_group
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct AmFollowingGroup {
+    pub id: String,
+}
+
+impl Action for AmFollowingGroup {
+    const NAME: &'static str = "am_following_group";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.followee_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FolloweeCount {
+    pub id: String,
+}
+
+impl Action for FolloweeCount {
+    const NAME: &'static str = "followee_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_followee_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserFolloweeCount {
+    pub id: String,
+}
+
+impl Action for UserFolloweeCount {
+    const NAME: &'static str = "user_followee_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_followee_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatasetFolloweeCount {
+    pub id: String,
+}
+
+impl Action for DatasetFolloweeCount {
+    const NAME: &'static str = "dataset_followee_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_followee_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupFolloweeCount {
+    pub id: String,
+}
+
+impl Action for GroupFolloweeCount {
+    const NAME: &'static str = "group_followee_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_followee_count
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationFolloweeCount {
+    pub id: String,
+}
+
+impl Action for OrganizationFolloweeCount {
+    const NAME: &'static str = "organization_followee_count";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.followee_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FolloweeList {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+}
+
+impl Action for FolloweeList {
+    const NAME: &'static str = "followee_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_followee_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserFolloweeList {
+    pub id: String,
+}
+
+impl Action for UserFolloweeList {
+    const NAME: &'static str = "user_followee_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_followee_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatasetFolloweeList {
+    pub id: String,
+}
+
+impl Action for DatasetFolloweeList {
+    const NAME: &'static str = "dataset_followee_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_followee_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupFolloweeList {
+    pub id: String,
+}
+
+impl Action for GroupFolloweeList {
+    const NAME: &'static str = "group_followee_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_followee_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationFolloweeList {
+    pub id: String,
+}
+
+impl Action for OrganizationFolloweeList {
+    const NAME: &'static str = "organization_followee_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.member_roles_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct MemberRolesList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_type: Option<String>,
+}
+
+impl Action for MemberRolesList {
+    const NAME: &'static str = "member_roles_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.help_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HelpShow {
+    pub name: String,
+}
+
+impl Action for HelpShow {
+    const NAME: &'static str = "help_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.config_option_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ConfigOptionShow {
+    pub key: String,
+}
+
+impl Action for ConfigOptionShow {
+    const NAME: &'static str = "config_option_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.config_option_list
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct ConfigOptionList;
+
+impl Action for ConfigOptionList {
+    const NAME: &'static str = "config_option_list";
+    type Response = serde_json::Value;
+    const GET: bool = true;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.job_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct JobList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queues: Option<Vec<String>>,
+}
+
+impl Action for JobList {
+    const NAME: &'static str = "job_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.job_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct JobShow {
+    pub id: String,
+}
+
+impl Action for JobShow {
+    const NAME: &'static str = "job_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.api_token_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ApiTokenList {
+    pub user_id: String,
+}
+
+impl Action for ApiTokenList {
+    const NAME: &'static str = "api_token_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.site_read
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct SiteRead;
+
+impl Action for SiteRead {
+    const NAME: &'static str = "site_read";
+    type Response = serde_json::Value;
+    const GET: bool = true;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ActivityShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_data: Option<bool>,
+}
+
+impl Action for ActivityShow {
+    const NAME: &'static str = "activity_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_data_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ActivityDataShow {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object_type: Option<String>,
+}
+
+impl Action for ActivityDataShow {
+    const NAME: &'static str = "activity_data_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_diff
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ActivityDiff {
+    pub id: String,
+    pub object_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff_type: Option<String>,
+}
+
+impl Action for ActivityDiff {
+    const NAME: &'static str = "activity_diff";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.package_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageActivityList {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_hidden_activity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_activity_types: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for PackageActivityList {
+    const NAME: &'static str = "package_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.group_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupActivityList {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_hidden_activity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for GroupActivityList {
+    const NAME: &'static str = "group_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.organization_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationActivityList {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_hidden_activity: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for OrganizationActivityList {
+    const NAME: &'static str = "organization_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.user_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserActivityList {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for UserActivityList {
+    const NAME: &'static str = "user_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.recently_changed_packages_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct RecentlyChangedPackagesActivityList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for RecentlyChangedPackagesActivityList {
+    const NAME: &'static str = "recently_changed_packages_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.dashboard_activity_list
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DashboardActivityList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+impl Action for DashboardActivityList {
+    const NAME: &'static str = "dashboard_activity_list";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.dashboard_new_activities_count
+#[derive(Debug, Clone, Copy, Default, Serialize)]
+pub struct DashboardNewActivitiesCount;
+
+impl Action for DashboardNewActivitiesCount {
+    const NAME: &'static str = "dashboard_new_activities_count";
+    type Response = serde_json::Value;
+    const GET: bool = true;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub private: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_object: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_subject: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for PackageCreate {
+    const NAME: &'static str = "package_create";
+    type Response = crate::types::Package;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceCreate {
+    pub package_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype_inner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_last_updated: Option<String>,
+    #[serde(skip)]
+    pub upload: Option<PathBuf>,
+}
+
+impl Action for ResourceCreate {
+    const NAME: &'static str = "resource_create";
+    type Response = crate::types::Resource;
+    fn upload(&self) -> Option<PathBuf> {
+        self.upload.clone()
+    }
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_view_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewCreate {
+    pub resource_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[builder(into)]
+    pub view_type: ViewType,
+    #[serde(flatten)]
+    pub config: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for ResourceViewCreate {
+    const NAME: &'static str = "resource_view_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_create_default_resource_views
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceCreateDefaultResourceViews {
+    pub resource: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_datastore_views: Option<bool>,
+}
+
+impl Action for ResourceCreateDefaultResourceViews {
+    const NAME: &'static str = "resource_create_default_resource_views";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_create_default_resource_views
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCreateDefaultResourceViews {
+    pub package: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_datastore_views: Option<bool>,
+}
+
+impl Action for PackageCreateDefaultResourceViews {
+    const NAME: &'static str = "package_create_default_resource_views";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_relationship_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageRelationshipCreate {
+    pub subject: String,
+    pub object: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl Action for PackageRelationshipCreate {
+    const NAME: &'static str = "package_relationship_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.member_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct MemberCreate {
+    pub id: String,
+    pub object: String,
+    #[builder(into)]
+    pub object_type: ObjectType,
+    #[builder(into)]
+    pub capacity: Role,
+}
+
+impl Action for MemberCreate {
+    const NAME: &'static str = "member_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_collaborator_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCollaboratorCreate {
+    pub id: String,
+    pub user_id: String,
+    #[builder(into)]
+    pub capacity: Role,
+}
+
+impl Action for PackageCollaboratorCreate {
+    const NAME: &'static str = "package_collaborator_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.group_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for GroupCreate {
+    const NAME: &'static str = "group_create";
+    type Response = crate::types::Group;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.organization_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationCreate {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for OrganizationCreate {
+    const NAME: &'static str = "organization_create";
+    type Response = crate::types::Group;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.user_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserCreate {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fullname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_extras: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_apitoken: Option<bool>,
+}
+
+impl Action for UserCreate {
+    const NAME: &'static str = "user_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.user_invite
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserInvite {
+    pub email: String,
+    pub group_id: String,
+    #[builder(into)]
+    pub role: Role,
+}
+
+impl Action for UserInvite {
+    const NAME: &'static str = "user_invite";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.vocabulary_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct VocabularyCreate {
+    pub name: String,
+    pub tags: Vec<serde_json::Value>,
+}
+
+impl Action for VocabularyCreate {
+    const NAME: &'static str = "vocabulary_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.tag_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagCreate {
+    pub name: String,
+    pub vocabulary_id: String,
+}
+
+impl Action for TagCreate {
+    const NAME: &'static str = "tag_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FollowUser {
+    pub id: String,
+}
+
+impl Action for FollowUser {
+    const NAME: &'static str = "follow_user";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_dataset
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FollowDataset {
+    pub id: String,
+}
+
+impl Action for FollowDataset {
+    const NAME: &'static str = "follow_dataset";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.group_member_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupMemberCreate {
+    pub id: String,
+    pub username: String,
+    #[builder(into)]
+    pub role: Role,
+}
+
+impl Action for GroupMemberCreate {
+    const NAME: &'static str = "group_member_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.organization_member_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationMemberCreate {
+    pub id: String,
+    pub username: String,
+    #[builder(into)]
+    pub role: Role,
+}
+
+impl Action for OrganizationMemberCreate {
+    const NAME: &'static str = "organization_member_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_group
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FollowGroup {
+    pub id: String,
+}
+
+impl Action for FollowGroup {
+    const NAME: &'static str = "follow_group";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.api_token_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ApiTokenCreate {
+    pub user: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<u32>,
+}
+
+impl Action for ApiTokenCreate {
+    const NAME: &'static str = "api_token_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceUpdate {
+    pub id: String,
+    pub package_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype_inner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_last_updated: Option<String>,
+    #[serde(skip)]
+    pub upload: Option<PathBuf>,
+}
+
+impl Action for ResourceUpdate {
+    const NAME: &'static str = "resource_update";
+    type Response = crate::types::Resource;
+    fn upload(&self) -> Option<PathBuf> {
+        self.upload.clone()
+    }
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_view_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewUpdate {
+    pub id: String,
+    pub resource_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[builder(into)]
+    pub view_type: ViewType,
+    #[serde(flatten)]
+    pub config: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for ResourceViewUpdate {
+    const NAME: &'static str = "resource_view_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_view_reorder
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewReorder {
+    pub id: String,
+    pub order: Vec<String>,
+}
+
+impl Action for ResourceViewReorder {
+    const NAME: &'static str = "resource_view_reorder";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageUpdate {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub private: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_object: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_subject: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for PackageUpdate {
+    const NAME: &'static str = "package_update";
+    type Response = crate::types::Package;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_revise
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageRevise {
+    #[serde(rename = "match")]
+    pub _match: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<Vec<String>>,
+    pub update: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<Vec<String>>,
+}
+
+impl Action for PackageRevise {
+    const NAME: &'static str = "package_revise";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_resource_reorder
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageResourceReorder {
+    pub id: String,
+    pub order: Vec<String>,
+}
+
+impl Action for PackageResourceReorder {
+    const NAME: &'static str = "package_resource_reorder";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_relationship_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageRelationshipUpdate {
+    pub subject: String,
+    pub object: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl Action for PackageRelationshipUpdate {
+    const NAME: &'static str = "package_relationship_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.group_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupUpdate {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+}
+
+impl Action for GroupUpdate {
+    const NAME: &'static str = "group_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.organization_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationUpdate {
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+}
+
+impl Action for OrganizationUpdate {
+    const NAME: &'static str = "organization_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.user_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserUpdate {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fullname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_extras: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_apitoken: Option<bool>,
+}
+
+impl Action for UserUpdate {
+    const NAME: &'static str = "user_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.task_status_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TaskStatusUpdate {
+    pub id: String,
+    pub entity_id: String,
+    pub entity_type: String,
+    pub task_type: String,
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl Action for TaskStatusUpdate {
+    const NAME: &'static str = "task_status_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.task_status_update_many
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TaskStatusUpdateMany {
+    pub data: Vec<serde_json::Value>,
+}
+
+impl Action for TaskStatusUpdateMany {
+    const NAME: &'static str = "task_status_update_many";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.term_translation_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TermTranslationUpdate {
+    pub term: String,
+    pub term_translation: String,
+    pub lang_code: String,
+}
+
+impl Action for TermTranslationUpdate {
+    const NAME: &'static str = "term_translation_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.term_translation_update_many
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TermTranslationUpdateMany {
+    pub data: Vec<serde_json::Value>,
+}
+
+impl Action for TermTranslationUpdateMany {
+    const NAME: &'static str = "term_translation_update_many";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.vocabulary_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct VocabularyUpdate {
+    pub id: String,
+    pub name: String,
+    pub tags: Vec<serde_json::Value>,
+}
+
+impl Action for VocabularyUpdate {
+    const NAME: &'static str = "vocabulary_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_owner_org_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageOwnerOrgUpdate {
+    pub id: String,
+    pub organization_id: String,
+}
+
+impl Action for PackageOwnerOrgUpdate {
+    const NAME: &'static str = "package_owner_org_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_private
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct BulkUpdatePrivate {
+    pub datasets: Vec<String>,
+    pub org_id: String,
+}
+
+impl Action for BulkUpdatePrivate {
+    const NAME: &'static str = "bulk_update_private";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_public
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct BulkUpdatePublic {
+    pub datasets: Vec<String>,
+    pub org_id: String,
+}
+
+impl Action for BulkUpdatePublic {
+    const NAME: &'static str = "bulk_update_public";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct BulkUpdateDelete {
+    pub datasets: Vec<String>,
+    pub org_id: String,
+}
+
+impl Action for BulkUpdateDelete {
+    const NAME: &'static str = "bulk_update_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.config_option_update
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ConfigOptionUpdate {
+    #[serde(flatten)]
+    pub options: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for ConfigOptionUpdate {
+    const NAME: &'static str = "config_option_update";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.package_patch
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackagePatch {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintainer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_object: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relationships_as_subject: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for PackagePatch {
+    const NAME: &'static str = "package_patch";
+    type Response = crate::types::Package;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.resource_patch
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourcePatch {
+    pub id: String,
+    pub package_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mimetype_inner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_last_updated: Option<String>,
+    #[serde(skip)]
+    pub upload: Option<PathBuf>,
+}
+
+impl Action for ResourcePatch {
+    const NAME: &'static str = "resource_patch";
+    type Response = crate::types::Resource;
+    fn upload(&self) -> Option<PathBuf> {
+        self.upload.clone()
+    }
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.group_patch
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupPatch {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub _type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for GroupPatch {
+    const NAME: &'static str = "group_patch";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.organization_patch
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationPatch {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extras: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub packages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users: Option<Vec<serde_json::Value>>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for OrganizationPatch {
+    const NAME: &'static str = "organization_patch";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.user_patch
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserPatch {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fullname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub about: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_extras: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_apitoken: Option<bool>,
+}
+
+impl Action for UserPatch {
+    const NAME: &'static str = "user_patch";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.user_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UserDelete {
+    pub id: String,
+}
+
+impl Action for UserDelete {
+    const NAME: &'static str = "user_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageDelete {
+    pub id: String,
+}
+
+impl Action for PackageDelete {
+    const NAME: &'static str = "package_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.dataset_purge
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatasetPurge {
+    pub id: String,
+}
+
+impl Action for DatasetPurge {
+    const NAME: &'static str = "dataset_purge";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceDelete {
+    pub id: String,
+}
+
+impl Action for ResourceDelete {
+    const NAME: &'static str = "resource_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_view_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewDelete {
+    pub id: String,
+}
+
+impl Action for ResourceViewDelete {
+    const NAME: &'static str = "resource_view_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_view_clear
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ResourceViewClear {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view_types: Option<Vec<String>>,
+}
+
+impl Action for ResourceViewClear {
+    const NAME: &'static str = "resource_view_clear";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_relationship_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageRelationshipDelete {
+    pub subject: String,
+    pub object: String,
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl Action for PackageRelationshipDelete {
+    const NAME: &'static str = "package_relationship_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.member_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct MemberDelete {
+    pub id: String,
+    pub object: String,
+    #[builder(into)]
+    pub object_type: ObjectType,
+}
+
+impl Action for MemberDelete {
+    const NAME: &'static str = "member_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_collaborator_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct PackageCollaboratorDelete {
+    pub id: String,
+    pub user_id: String,
+}
+
+impl Action for PackageCollaboratorDelete {
+    const NAME: &'static str = "package_collaborator_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupDelete {
+    pub id: String,
+}
+
+impl Action for GroupDelete {
+    const NAME: &'static str = "group_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationDelete {
+    pub id: String,
+}
+
+impl Action for OrganizationDelete {
+    const NAME: &'static str = "organization_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_purge
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupPurge {
+    pub id: String,
+}
+
+impl Action for GroupPurge {
+    const NAME: &'static str = "group_purge";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_purge
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationPurge {
+    pub id: String,
+}
+
+impl Action for OrganizationPurge {
+    const NAME: &'static str = "organization_purge";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.task_status_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TaskStatusDelete {
+    pub id: String,
+}
+
+impl Action for TaskStatusDelete {
+    const NAME: &'static str = "task_status_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.vocabulary_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct VocabularyDelete {
+    pub id: String,
+}
+
+impl Action for VocabularyDelete {
+    const NAME: &'static str = "vocabulary_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.tag_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct TagDelete {
+    pub id: String,
+    pub vocabulary_id: String,
+}
+
+impl Action for TagDelete {
+    const NAME: &'static str = "tag_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_user
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UnfollowUser {
+    pub id: String,
+}
+
+impl Action for UnfollowUser {
+    const NAME: &'static str = "unfollow_user";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_dataset
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UnfollowDataset {
+    pub id: String,
+}
+
+impl Action for UnfollowDataset {
+    const NAME: &'static str = "unfollow_dataset";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_member_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct GroupMemberDelete {
+    pub id: String,
+    pub username: String,
+}
+
+impl Action for GroupMemberDelete {
+    const NAME: &'static str = "group_member_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_member_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct OrganizationMemberDelete {
+    pub id: String,
+    pub username: String,
+}
+
+impl Action for OrganizationMemberDelete {
+    const NAME: &'static str = "organization_member_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_group
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct UnfollowGroup {
+    pub id: String,
+}
+
+impl Action for UnfollowGroup {
+    const NAME: &'static str = "unfollow_group";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.job_clear
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct JobClear {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queues: Option<Vec<String>>,
+}
+
+impl Action for JobClear {
+    const NAME: &'static str = "job_clear";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.job_cancel
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct JobCancel {
+    pub id: String,
+}
+
+impl Action for JobCancel {
+    const NAME: &'static str = "job_cancel";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.api_token_revoke
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct ApiTokenRevoke {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+}
+
+impl Action for ApiTokenRevoke {
+    const NAME: &'static str = "api_token_revoke";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-scheming#scheming_dataset_schema_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct SchemingDatasetSchemaShow {
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expanded: Option<bool>,
+}
+
+impl Action for SchemingDatasetSchemaShow {
+    const NAME: &'static str = "scheming_dataset_schema_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+    #[serde(skip)]
+    pub upload: Option<PathBuf>,
+}
+
+impl Action for FileCreate {
+    const NAME: &'static str = "file_create";
+    type Response = serde_json::Value;
+    fn upload(&self) -> Option<PathBuf> {
+        self.upload.clone()
+    }
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_register
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileRegister {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<String>,
+}
+
+impl Action for FileRegister {
+    const NAME: &'static str = "file_register";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileDelete {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl Action for FileDelete {
+    const NAME: &'static str = "file_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_show
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileShow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl Action for FileShow {
+    const NAME: &'static str = "file_show";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_rename
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileRename {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl Action for FileRename {
+    const NAME: &'static str = "file_rename";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_pin
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FilePin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl Action for FilePin {
+    const NAME: &'static str = "file_pin";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_unpin
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileUnpin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl Action for FileUnpin {
+    const NAME: &'static str = "file_unpin";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_ownership_transfer
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct FileOwnershipTransfer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_type: Option<OwnerType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin: Option<bool>,
+}
+
+impl Action for FileOwnershipTransfer {
+    const NAME: &'static str = "file_ownership_transfer";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/create.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceCreate {
+    pub url: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    pub source_type: String,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<HarvestFrequency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for HarvestSourceCreate {
+    const NAME: &'static str = "harvest_source_create";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceUpdate {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<HarvestFrequency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for HarvestSourceUpdate {
+    const NAME: &'static str = "harvest_source_update";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/patch.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourcePatch {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<HarvestFrequency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_org: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(flatten)]
+    pub custom_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl Action for HarvestSourcePatch {
+    const NAME: &'static str = "harvest_source_patch";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceShow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Action for HarvestSourceShow {
+    const NAME: &'static str = "harvest_source_show";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub only_active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_last_job_status: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Action for HarvestSourceList {
+    const NAME: &'static str = "harvest_source_list";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/delete.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceDelete {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear: Option<bool>,
+}
+
+impl Action for HarvestSourceDelete {
+    const NAME: &'static str = "harvest_source_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourceClear {
+    pub id: String,
+}
+
+impl Action for HarvestSourceClear {
+    const NAME: &'static str = "harvest_source_clear";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestSourcesJobHistoryClear {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keep_current: Option<bool>,
+}
+
+impl Action for HarvestSourcesJobHistoryClear {
+    const NAME: &'static str = "harvest_sources_job_history_clear";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/create.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestJobCreate {
+    pub source_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run: Option<bool>,
+}
+
+impl Action for HarvestJobCreate {
+    const NAME: &'static str = "harvest_job_create";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestJobList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<HarvestJobStatus>,
+}
+
+impl Action for HarvestJobList {
+    const NAME: &'static str = "harvest_job_list";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestJobShow {
+    pub id: String,
+}
+
+impl Action for HarvestJobShow {
+    const NAME: &'static str = "harvest_job_show";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestJobAbort {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+impl Action for HarvestJobAbort {
+    const NAME: &'static str = "harvest_job_abort";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestObjectShow {
+    pub id: String,
+}
+
+impl Action for HarvestObjectShow {
+    const NAME: &'static str = "harvest_object_show";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct HarvestLogList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+}
+
+impl Action for HarvestLogList {
+    const NAME: &'static str = "harvest_log_list";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-xloader/blob/master/ckanext/xloader/action.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct XloaderSubmit {
+    pub resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_hash: Option<bool>,
+}
+
+impl Action for XloaderSubmit {
+    const NAME: &'static str = "xloader_submit";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckanext-xloader/blob/master/ckanext/xloader/action.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct XloaderStatus {
+    pub resource_id: String,
+}
+
+impl Action for XloaderStatus {
+    const NAME: &'static str = "xloader_status";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckan/blob/2.10/ckanext/datapusher/logic/action.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatapusherSubmit {
+    pub resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_hash: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_url_type: Option<bool>,
+}
+
+impl Action for DatapusherSubmit {
+    const NAME: &'static str = "datapusher_submit";
+    type Response = serde_json::Value;
+}
+
+/// https://github.com/ckan/ckan/blob/2.10/ckanext/datapusher/logic/action.py
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatapusherStatus {
+    pub resource_id: String,
+}
+
+impl Action for DatapusherStatus {
+    const NAME: &'static str = "datapusher_status";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_create
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreCreate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource: Option<serde_json::Value>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aliases: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub records: Option<Vec<serde_json::Value>>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_key: Option<serde_json::Value>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triggers: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculate_record_count: Option<bool>,
+}
+
+impl Action for DatastoreCreate {
+    const NAME: &'static str = "datastore_create";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_upsert
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreUpsert {
+    pub resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub records: Option<Vec<serde_json::Value>>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<UpsertMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculate_record_count: Option<bool>,
+}
+
+impl Action for DatastoreUpsert {
+    const NAME: &'static str = "datastore_upsert";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_delete
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreDelete {
+    pub resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calculate_record_count: Option<bool>,
+}
+
+impl Action for DatastoreDelete {
+    const NAME: &'static str = "datastore_delete";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_info
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreInfo {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_meta: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_fields_schema: Option<bool>,
+}
+
+impl Action for DatastoreInfo {
+    const NAME: &'static str = "datastore_info";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_search
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreSearch {
+    pub resource_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<serde_json::Value>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plain: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_total: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_estimation_threshold: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub records_format: Option<String>,
+}
+
+impl Action for DatastoreSearch {
+    const NAME: &'static str = "datastore_search";
+    type Response = serde_json::Value;
+}
+
+/// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_search_sql
+#[derive(Debug, Clone, Serialize, bon::Builder)]
+#[builder(on(String, into))]
+pub struct DatastoreSearchSql {
+    pub sql: String,
+}
+
+impl Action for DatastoreSearchSql {
+    const NAME: &'static str = "datastore_search_sql";
+    type Response = serde_json::Value;
+}
@@ -0,0 +1,163 @@
+//! Run many create/patch/delete actions concurrently over a shared `CKAN`.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::bulk::Operation;
+//! use serde_json::json;
+//!
+//! let operations = (0..5000).map(|i| Operation::PackagePatch(json!({
+//!     "id": format!("dataset-{i}"),
+//!     "license_id": "cc-by",
+//! })));
+//! let results = ckan
+//!     .bulk()
+//!     .operations(operations)
+//!     .concurrency(16)
+//!     .on_progress(&|progress| println!("{}/{}", progress.completed, progress.total))
+//!     .call()
+//!     .await;
+//! let failed = results.iter().filter(|item| item.result.is_err()).count();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    CKAN, CKANError,
+    params::{ObjectType, Role},
+};
+use bon::bon;
+use futures::{StreamExt, stream};
+use serde_json::json;
+
+/// A single mutating action. Package and resource bodies are sent as-is, so any field accepted
+/// by the action (including custom fields) can be used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+    PackageCreate(serde_json::Value),
+    PackagePatch(serde_json::Value),
+    PackageDelete {
+        id: String,
+    },
+    ResourceCreate(serde_json::Value),
+    ResourcePatch(serde_json::Value),
+    ResourceDelete {
+        id: String,
+    },
+    MemberCreate {
+        id: String,
+        object: String,
+        object_type: ObjectType,
+        capacity: Role,
+    },
+    MemberDelete {
+        id: String,
+        object: String,
+        object_type: ObjectType,
+    },
+}
+
+impl Operation {
+    /// Name of the CKAN action this operation calls.
+    pub fn action(&self) -> &'static str {
+        match self {
+            Self::PackageCreate(_) => "package_create",
+            Self::PackagePatch(_) => "package_patch",
+            Self::PackageDelete { .. } => "package_delete",
+            Self::ResourceCreate(_) => "resource_create",
+            Self::ResourcePatch(_) => "resource_patch",
+            Self::ResourceDelete { .. } => "resource_delete",
+            Self::MemberCreate { .. } => "member_create",
+            Self::MemberDelete { .. } => "member_delete",
+        }
+    }
+
+    /// JSON body sent to the action.
+    pub fn body(&self) -> serde_json::Value {
+        match self {
+            Self::PackageCreate(body)
+            | Self::PackagePatch(body)
+            | Self::ResourceCreate(body)
+            | Self::ResourcePatch(body) => body.clone(),
+            Self::PackageDelete { id } | Self::ResourceDelete { id } => json!({ "id": id }),
+            Self::MemberCreate {
+                id,
+                object,
+                object_type,
+                capacity,
+            } => json!({
+                "id": id,
+                "object": object,
+                "object_type": object_type,
+                "capacity": capacity,
+            }),
+            Self::MemberDelete {
+                id,
+                object,
+                object_type,
+            } => json!({ "id": id, "object": object, "object_type": object_type }),
+        }
+    }
+}
+
+/// Result of one operation. `result` holds the action's `result`, or the error it failed with.
+#[derive(Debug)]
+pub struct BulkResult {
+    /// Position of the operation in the input.
+    pub index: usize,
+    pub operation: Operation,
+    pub result: Result<serde_json::Value, CKANError>,
+}
+
+/// Emitted every time an operation finishes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BulkProgress {
+    /// Position of the finished operation in the input.
+    pub index: usize,
+    pub success: bool,
+    /// Number of operations finished so far, including this one.
+    pub completed: usize,
+    pub total: usize,
+}
+
+#[bon]
+impl CKAN {
+    /// Execute `operations` with at most `concurrency` (4 by default) requests in flight.
+    ///
+    /// Failing operations do not stop the others. Results are returned in input order.
+    #[builder]
+    pub async fn bulk<I: IntoIterator<Item = Operation>>(
+        &self,
+        operations: I,
+        concurrency: Option<usize>,
+        on_progress: Option<&(dyn Fn(&BulkProgress) + Sync)>,
+    ) -> Vec<BulkResult> {
+        let operations: Vec<Operation> = operations.into_iter().collect();
+        let total = operations.len();
+        let mut completed = 0;
+        let mut results: Vec<BulkResult> = stream::iter(operations.into_iter().enumerate())
+            .map(|(index, operation)| async move {
+                let result = self.call_action(operation.action(), operation.body()).await;
+                BulkResult {
+                    index,
+                    operation,
+                    result,
+                }
+            })
+            .buffer_unordered(concurrency.unwrap_or(4).max(1))
+            .inspect(|item| {
+                completed += 1;
+                if let Some(on_progress) = on_progress {
+                    on_progress(&BulkProgress {
+                        index: item.index,
+                        success: item.result.is_ok(),
+                        completed,
+                        total,
+                    });
+                }
+            })
+            .collect()
+            .await;
+        results.sort_by_key(|item| item.index);
+        results
+    }
+}
@@ -0,0 +1,108 @@
+//! Discover which extensions the server runs.
+//!
+//! Whether actions such as `datastore_search` or `harvest_source_list` exist depends on the
+//! plugins enabled on the server. `status_show` lists them; when a plugin is not listed under the
+//! expected name the action itself is looked up with `help_show`. Both answers are cached on the
+//! `CKAN`.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! let capabilities = ckan.capabilities().await?;
+//! if capabilities.has_extension("datastore") {
+//!     println!("DataStore is enabled");
+//! }
+//! // Fails with `CKANError::Unsupported` when ckanext-harvest is missing.
+//! ckan.require_extension("harvest", "harvest_source_list").await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError, action_result, version::Version};
+use serde_json::json;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    sync::{Mutex, OnceLock},
+};
+
+/// What `status_show` reports about the server.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    pub version: Option<Version>,
+    /// Names of the enabled plugins, e.g. `datastore`, `harvest` or `scheming_datasets`.
+    pub extensions: BTreeSet<String>,
+}
+
+impl Capabilities {
+    pub fn has_extension(&self, extension: &str) -> bool {
+        self.extensions.contains(extension)
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Cache {
+    status: OnceLock<Capabilities>,
+    actions: Mutex<HashMap<String, bool>>,
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache").finish_non_exhaustive()
+    }
+}
+
+impl CKAN {
+    /// The CKAN version and enabled extensions, read from `status_show` on the first call.
+    pub async fn capabilities(&self) -> Result<Capabilities, CKANError> {
+        if let Some(capabilities) = self.capabilities.status.get() {
+            return Ok(capabilities.clone());
+        }
+        let status = action_result(self.status_show().await?)?;
+        let capabilities = Capabilities {
+            version: status["ckan_version"].as_str().and_then(Version::parse),
+            extensions: status["extensions"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|extension| Some(extension.as_str()?.to_string()))
+                .collect(),
+        };
+        Ok(self
+            .capabilities
+            .status
+            .get_or_init(|| capabilities)
+            .clone())
+    }
+
+    /// Whether the server provides `action`, looked up with `help_show`.
+    pub async fn has_action(&self, action: &str) -> Result<bool, CKANError> {
+        if let Some(known) = self.capabilities.actions.lock().unwrap().get(action) {
+            return Ok(*known);
+        }
+        let known = match self
+            .call_action("help_show", json!({ "name": action }))
+            .await
+        {
+            Ok(_) => true,
+            Err(CKANError::ActionError(error)) if error["__type"] == "Not Found Error" => false,
+            Err(error) => return Err(error),
+        };
+        self.capabilities
+            .actions
+            .lock()
+            .unwrap()
+            .insert(action.to_string(), known);
+        Ok(known)
+    }
+
+    /// Fail with `CKANError::Unsupported` unless `extension` is enabled. If it is not listed by
+    /// `status_show`, e.g. because the plugin has another name, `action` is looked up instead.
+    pub async fn require_extension(&self, extension: &str, action: &str) -> Result<(), CKANError> {
+        if self.capabilities().await?.has_extension(extension) || self.has_action(action).await? {
+            return Ok(());
+        }
+        Err(CKANError::Unsupported(format!(
+            "{action} is not available, the {extension} extension is not enabled on the server"
+        )))
+    }
+}
@@ -0,0 +1,394 @@
+//! Generate serde structs from a ckanext-scheming schema at build time.
+//!
+//! Generate the types in `build.rs`:
+//!
+//! ```no_run
+//! fn main() -> Result<(), ckanaction::CKANError> {
+//!     ckanaction::codegen::generate("schemas/dataset.yaml")
+//! }
+//! ```
+//!
+//! Then include them with [`scheming!`](crate::scheming!) using the file stem of the schema. A
+//! `dataset` schema gives a `DatasetFields` struct for the dataset fields and a
+//! `DatasetResourceFields` struct for the resource fields, plus an enum for every field with
+//! `choices`, whose `Other` variant keeps values the schema does not list:
+//!
+//! ```ignore
+//! ckanaction::scheming!("dataset");
+//!
+//! use ckanaction::scheming::SchemingFields;
+//!
+//! let fields = DatasetFields {
+//!     frequency: Some(DatasetFrequency::Monthly),
+//!     ..Default::default()
+//! };
+//! ckan.package_create()
+//!     .name("air-quality")
+//!     .private(false)
+//!     .custom_fields(fields.to_custom_fields()?)
+//!     .call()
+//!     .await?;
+//!
+//! let package: ckanaction::types::Package = serde_json::from_value(
+//!     ckan.package_show().id("air-quality").call().await?["result"].take(),
+//! )?;
+//! let fields = DatasetFields::from_custom_fields(&package.custom_fields)?;
+//! ```
+//!
+//! Fields already typed in `types::Package` and `types::Resource` are left out. Integer and
+//! boolean fields are also read from the strings CKAN returns for extras. Every field is
+//! an `Option` so partial payloads can be built; required fields are checked by
+//! `scheming::Schema` validation. The crate using the generated code needs `serde` (with the
+//! `derive` feature) and `serde_json` as dependencies.
+
+use crate::{CKANError, scheming::Field, scheming::Schema};
+use std::{fmt::Write, path::Path};
+
+const PACKAGE_FIELDS: [&str; 23] = [
+    "id",
+    "name",
+    "title",
+    "private",
+    "author",
+    "author_email",
+    "maintainer",
+    "maintainer_email",
+    "license_id",
+    "notes",
+    "url",
+    "version",
+    "state",
+    "type",
+    "owner_org",
+    "metadata_created",
+    "metadata_modified",
+    "organization",
+    "resources",
+    "tags",
+    "tag_string",
+    "groups",
+    "extras",
+];
+const RESOURCE_FIELDS: [&str; 14] = [
+    "id",
+    "package_id",
+    "name",
+    "description",
+    "url",
+    "format",
+    "mimetype",
+    "size",
+    "url_type",
+    "datastore_active",
+    "position",
+    "state",
+    "created",
+    "last_modified",
+];
+const KEYWORDS: [&str; 49] = [
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "try", "type", "union", "unsafe", "use",
+    "where", "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Split `name` on anything that is not alphanumeric and capitalize each part.
+fn pascal_case(name: &str) -> String {
+    let pascal: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().unwrap().to_ascii_uppercase().to_string() + chars.as_str()
+        })
+        .collect();
+    match pascal.chars().next() {
+        None => "Empty".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("V{pascal}"),
+        Some(_) if pascal == "Self" => "Self_".to_string(),
+        Some(_) => pascal,
+    }
+}
+
+fn field_ident(name: &str) -> String {
+    let ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match ident.chars().next() {
+        None => "_empty".to_string(),
+        // `_` alone is not an identifier.
+        _ if ident.chars().all(|c| c == '_') => format!("field{ident}"),
+        Some(first) if first.is_ascii_digit() => format!("_{ident}"),
+        _ if ["crate", "self", "super"].contains(&ident.as_str()) => format!("{ident}_"),
+        _ => ident,
+    }
+}
+
+/// `name`, or `name` followed by `separator` and the first number from 2 that is not in `used`.
+fn unique(name: String, separator: &str, used: &mut Vec<String>) -> String {
+    let name = if used.contains(&name) {
+        (2..)
+            .map(|n| format!("{name}{separator}{n}"))
+            .find(|candidate| !used.contains(candidate))
+            .unwrap()
+    } else {
+        name
+    };
+    used.push(name.clone());
+    name
+}
+
+fn raw(ident: &str) -> String {
+    if KEYWORDS.contains(&ident) {
+        format!("r#{ident}")
+    } else {
+        ident.to_string()
+    }
+}
+
+fn validators(field: &Field) -> Vec<&str> {
+    field
+        .validators
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|validator| validator.split('(').next().unwrap_or_default())
+        .collect()
+}
+
+fn is_multiple(field: &Field) -> bool {
+    matches!(
+        field.preset.as_deref(),
+        Some("multiple_checkbox" | "multiple_select" | "multiple_text")
+    ) || validators(field).contains(&"scheming_multiple_choice")
+}
+
+/// Rust type of `field`, where `choices` is the name of its enum if it has one.
+fn field_type(field: &Field, choices: Option<&str>) -> String {
+    let validators = validators(field);
+    let item = match choices {
+        Some(choices) => choices.to_string(),
+        None if validators.contains(&"int_validator") => "i64".to_string(),
+        None if validators.contains(&"boolean_validator")
+            || field.preset.as_deref() == Some("checkbox") =>
+        {
+            "bool".to_string()
+        }
+        None if validators.contains(&"scheming_valid_json_object")
+            || field.preset.as_deref() == Some("json_object") =>
+        {
+            "serde_json::Value".to_string()
+        }
+        None => "String".to_string(),
+    };
+    if is_multiple(field) {
+        format!("Vec<{item}>")
+    } else {
+        item
+    }
+}
+
+fn write_enum(code: &mut String, name: &str, field: &Field) {
+    let mut variants = vec!["Other".to_string()];
+    let mut values: Vec<(&str, String)> = vec![];
+    for choice in field.choices.iter().flatten() {
+        if !values.iter().any(|(value, _)| *value == choice.value) {
+            let variant = unique(pascal_case(&choice.value), "", &mut variants);
+            values.push((&choice.value, variant));
+        }
+    }
+    writeln!(code, "/// Choices of `{}`.", field.field_name).unwrap();
+    writeln!(code, "#[derive(Debug, Clone, PartialEq, Eq, Hash)]").unwrap();
+    writeln!(code, "pub enum {name} {{").unwrap();
+    for (_, variant) in &values {
+        writeln!(code, "    {variant},").unwrap();
+    }
+    writeln!(
+        code,
+        "    /// Any value not among the choices of the schema.\n    Other(String),\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "impl {name} {{\n    pub fn as_str(&self) -> &str {{\n        match self {{"
+    )
+    .unwrap();
+    for (value, variant) in &values {
+        writeln!(code, "            Self::{variant} => {value:?},").unwrap();
+    }
+    writeln!(
+        code,
+        "            Self::Other(value) => value,\n        }}\n    }}\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "impl From<&str> for {name} {{\n    fn from(value: &str) -> Self {{\n        match value {{"
+    )
+    .unwrap();
+    for (value, variant) in &values {
+        writeln!(code, "            {value:?} => Self::{variant},").unwrap();
+    }
+    writeln!(
+        code,
+        "            other => Self::Other(other.to_string()),\n        }}\n    }}\n}}\n"
+    )
+    .unwrap();
+    writeln!(
+        code,
+        "impl From<String> for {name} {{
+    fn from(value: String) -> Self {{
+        Self::from(value.as_str())
+    }}
+}}
+
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.write_str(self.as_str())
+    }}
+}}
+
+impl serde::Serialize for {name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        serializer.serialize_str(self.as_str())
+    }}
+}}
+
+impl<'de> serde::Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        Ok(Self::from(<String as serde::Deserialize>::deserialize(deserializer)?))
+    }}
+}}
+"
+    )
+    .unwrap();
+}
+
+fn write_struct(
+    code: &mut String,
+    name: &str,
+    fields: &[Field],
+    core: &[&str],
+    about: &str,
+    types: &mut Vec<String>,
+) {
+    let fields: Vec<&Field> = fields
+        .iter()
+        .filter(|field| !core.contains(&field.field_name.as_str()))
+        .collect();
+    let mut members = String::new();
+    let mut idents = vec![];
+    for field in fields {
+        let choices = field.choices.as_ref().map(|_| {
+            let name = unique(
+                name.trim_end_matches("Fields").to_string() + &pascal_case(&field.field_name),
+                "",
+                types,
+            );
+            write_enum(code, &name, field);
+            name
+        });
+        let ident = unique(field_ident(&field.field_name), "_", &mut idents);
+        if ident != field.field_name {
+            writeln!(members, "    #[serde(rename = {:?})]", field.field_name).unwrap();
+        }
+        let ty = field_type(field, choices.as_deref());
+        // Fields stored as extras come back from CKAN as strings.
+        if ty == "i64" || ty == "bool" {
+            writeln!(
+                members,
+                "    #[serde(deserialize_with = \"ckanaction::scheming::string_or_value\")]"
+            )
+            .unwrap();
+        }
+        writeln!(
+            members,
+            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub {}: Option<{ty}>,",
+            raw(&ident),
+        )
+        .unwrap();
+    }
+    writeln!(code, "/// {about}").unwrap();
+    writeln!(
+        code,
+        "#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]"
+    )
+    .unwrap();
+    writeln!(code, "pub struct {name} {{\n{members}}}\n").unwrap();
+    writeln!(
+        code,
+        "impl ckanaction::scheming::SchemingFields for {name} {{}}\n"
+    )
+    .unwrap();
+}
+
+/// Rust source of the structs and enums for `schema`.
+pub fn rust_types(schema: &Schema) -> String {
+    let prefix = pascal_case(&schema.dataset_type);
+    let mut types = vec![format!("{prefix}Fields"), format!("{prefix}ResourceFields")];
+    let mut code = format!(
+        "// Generated by ckanaction from the `{}` scheming schema. Do not edit.\n\n",
+        schema.dataset_type
+    );
+    write_struct(
+        &mut code,
+        &format!("{prefix}Fields"),
+        &schema.dataset_fields,
+        &PACKAGE_FIELDS,
+        &format!("Custom fields of `{}` datasets.", schema.dataset_type),
+        &mut types,
+    );
+    write_struct(
+        &mut code,
+        &format!("{prefix}ResourceFields"),
+        &schema.resource_fields,
+        &RESOURCE_FIELDS,
+        &format!(
+            "Custom fields of the resources of `{}` datasets.",
+            schema.dataset_type
+        ),
+        &mut types,
+    );
+    code.truncate(code.trim_end().len());
+    code.push('\n');
+    code
+}
+
+/// Generate the types for the schema at `path` (relative to the package root) into
+/// `OUT_DIR/scheming/<file stem>.rs`, where [`scheming!`](crate::scheming!) finds them. Meant to
+/// be called from `build.rs`.
+pub fn generate(path: impl AsRef<Path>) -> Result<(), CKANError> {
+    let path = path.as_ref();
+    println!("cargo:rerun-if-changed={}", path.display());
+    let out_dir = std::env::var("OUT_DIR").map_err(std::io::Error::other)?;
+    let stem = path.file_stem().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("{} has no file name", path.display()),
+        )
+    })?;
+    let out = Path::new(&out_dir).join("scheming");
+    std::fs::create_dir_all(&out)?;
+    std::fs::write(
+        out.join(stem).with_extension("rs"),
+        rust_types(&Schema::from_path(path)?),
+    )?;
+    Ok(())
+}
+
+/// Include the types generated by [`codegen::generate`](crate::codegen::generate) for the
+/// schema with the given file stem, e.g. `"dataset"` for `schemas/dataset.yaml`.
+#[macro_export]
+macro_rules! scheming {
+    ($stem: literal) => {
+        include!(concat!(env!("OUT_DIR"), "/scheming/", $stem, ".rs"));
+    };
+}
@@ -0,0 +1,184 @@
+//! Where the API token of a client comes from.
+//!
+//! A [`CredentialProvider`] is asked for the token on every request, and asked to refresh it when
+//! the server responds `401 Unauthorized`, after which the request is sent once more. Tokens can
+//! come from a string, an environment variable, a file that is rotated by another process, or the
+//! output of a command such as a password manager. OS keyrings are read through their command
+//! line tools with a [`CommandToken`], e.g. `secret-tool lookup service ckan` on Linux or
+//! `security find-generic-password -s ckan -w` on macOS:
+//!
+//! ```no_run
+//! use ckanaction::{
+//!     CKAN,
+//!     credentials::{CommandToken, FileToken},
+//! };
+//! use std::sync::Arc;
+//!
+//! let ckan = CKAN::builder()
+//!     .url("https://demo.ckan.org")
+//!     .credentials(Arc::new(CommandToken::new("pass", ["show", "ckan/prod"])))
+//!     .build();
+//!
+//! // Or a token file kept up to date by a sidecar:
+//! ckan.set_credentials(Some(Arc::new(FileToken::new("/run/secrets/ckan-token"))));
+//! ```
+
+use crate::CKANError;
+use futures::{FutureExt, future::BoxFuture};
+use std::{any::Any, fmt, path::PathBuf};
+use tokio::{process::Command, sync::Mutex};
+
+/// A source of API tokens. Both methods return futures so that providers can read files or run
+/// commands without blocking the runtime.
+pub trait CredentialProvider: Any + fmt::Debug + Send + Sync {
+    /// The token to send, or `None` to send requests without one.
+    fn token(&self) -> BoxFuture<'_, Result<Option<String>, CKANError>>;
+
+    /// Called when the server rejected the token. Returns whether a retry may use a different
+    /// token. Does nothing by default.
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, CKANError>> {
+        async { Ok(false) }.boxed()
+    }
+}
+
+/// A fixed token, as set with the `token` builder parameter.
+pub struct StaticToken(pub String);
+
+impl fmt::Debug for StaticToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StaticToken").finish_non_exhaustive()
+    }
+}
+
+impl CredentialProvider for StaticToken {
+    fn token(&self) -> BoxFuture<'_, Result<Option<String>, CKANError>> {
+        async { Ok(Some(self.0.clone())) }.boxed()
+    }
+}
+
+/// Whether `provider` is a fixed token.
+pub(crate) fn is_static(provider: &dyn CredentialProvider) -> bool {
+    (provider as &dyn Any).is::<StaticToken>()
+}
+
+/// The value of an environment variable, read on every request. No token is sent while it is
+/// unset.
+#[derive(Debug, Clone)]
+pub struct EnvToken {
+    pub variable: String,
+}
+
+impl EnvToken {
+    pub fn new(variable: impl Into<String>) -> Self {
+        Self {
+            variable: variable.into(),
+        }
+    }
+}
+
+impl CredentialProvider for EnvToken {
+    fn token(&self) -> BoxFuture<'_, Result<Option<String>, CKANError>> {
+        async {
+            Ok(std::env::var(&self.variable)
+                .ok()
+                .filter(|token| !token.is_empty()))
+        }
+        .boxed()
+    }
+}
+
+/// The contents of a file without surrounding whitespace, read on every request so that a
+/// rotated token is picked up right away.
+#[derive(Debug, Clone)]
+pub struct FileToken {
+    pub path: PathBuf,
+}
+
+impl FileToken {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl CredentialProvider for FileToken {
+    fn token(&self) -> BoxFuture<'_, Result<Option<String>, CKANError>> {
+        async {
+            let token = tokio::fs::read_to_string(&self.path).await?;
+            Ok(Some(token.trim().to_string()).filter(|token| !token.is_empty()))
+        }
+        .boxed()
+    }
+
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, CKANError>> {
+        async { Ok(true) }.boxed()
+    }
+}
+
+/// The output of a command without surrounding whitespace. The command runs for the first request
+/// and again after the token is rejected. Concurrent requests wait for the same run.
+pub struct CommandToken {
+    pub program: String,
+    pub args: Vec<String>,
+    token: Mutex<Option<String>>,
+}
+
+impl CommandToken {
+    pub fn new(
+        program: impl Into<String>,
+        args: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            program: program.into(),
+            args: args.into_iter().map(Into::into).collect(),
+            token: Mutex::new(None),
+        }
+    }
+
+    async fn run(&self) -> Result<String, CKANError> {
+        let output = Command::new(&self.program)
+            .args(&self.args)
+            .output()
+            .await?;
+        if !output.status.success() {
+            return Err(CKANError::CredentialError(format!(
+                "{} exited with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        String::from_utf8(output.stdout)
+            .map(|token| token.trim().to_string())
+            .map_err(|error| CKANError::CredentialError(error.to_string()))
+    }
+}
+
+impl fmt::Debug for CommandToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CommandToken")
+            .field("program", &self.program)
+            .field("args", &self.args)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CredentialProvider for CommandToken {
+    fn token(&self) -> BoxFuture<'_, Result<Option<String>, CKANError>> {
+        async {
+            let mut token = self.token.lock().await;
+            if token.is_none() {
+                *token = Some(self.run().await?);
+            }
+            Ok(token.clone().filter(|token| !token.is_empty()))
+        }
+        .boxed()
+    }
+
+    fn refresh(&self) -> BoxFuture<'_, Result<bool, CKANError>> {
+        async {
+            *self.token.lock().await = None;
+            Ok(true)
+        }
+        .boxed()
+    }
+}
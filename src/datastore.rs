@@ -0,0 +1,658 @@
+//! Load tabular files into the DataStore and read DataStore tables back.
+//!
+//! [`load`] reads a CSV or JSON Lines file (or Parquet with the `parquet`
+//! feature), infers the field types from the first rows unless `fields` are given, creates the
+//! table with `datastore_create` and writes the rows with `datastore_upsert` in batches:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::CKANError;
+//!
+//! let load = ckanaction::datastore::load(&ckan)
+//!     .resource_id("0b7f5d5e-8c1a-4c1e-9a44-1c3f2b0e6f7a")
+//!     .path("measurements.csv".into())
+//!     .primary_key(vec!["station".to_string(), "time".to_string()])
+//!     .batch_size(5000)
+//!     .call()
+//!     .await;
+//! match load {
+//!     Ok(report) => println!("{} rows loaded", report.rows),
+//!     // Run the load again with `.skip_rows(rows)` to continue where it stopped.
+//!     Err(CKANError::LoadInterrupted { rows, .. }) => println!("stopped after {rows} rows"),
+//!     Err(error) => return Err(error),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`CKAN::datastore_records`] pages through a `datastore_search` as a stream of rows
+//! deserialized into your own type, and [`CKAN::datastore_table`] fetches all rows with their
+//! field types, to be written as CSV or, with the `arrow` and `parquet` features, turned into an
+//! Arrow `RecordBatch` or written as Parquet. With the `arrow` feature,
+//! `CKAN::datastore_record_batches` streams one `RecordBatch` per page instead.
+
+use crate::{CKAN, CKANError, action_result, params::DatastoreType};
+use bon::{bon, builder};
+use futures::{StreamExt, stream};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::{
+    io::BufRead,
+    path::{Path, PathBuf},
+    sync::LazyLock,
+};
+
+static DATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
+static TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\d{4}-\d{2}-\d{2}[T ]\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:?\d{2})?$").unwrap()
+});
+static NUMBER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^-?(0|[1-9]\d*)(\.\d+)?([eE][+-]?\d+)?$").unwrap());
+
+/// Number of rows the field types are inferred from.
+const SAMPLE_SIZE: usize = 1000;
+
+/// A field of a DataStore table, as passed to `datastore_create` and returned by
+/// `datastore_search` and `datastore_info`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub _type: DatastoreType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub info: Option<Value>,
+}
+
+impl Field {
+    pub fn new(id: impl Into<String>, _type: impl Into<DatastoreType>) -> Self {
+        Self {
+            id: id.into(),
+            _type: _type.into(),
+            info: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    /// One JSON object per line.
+    JsonLines,
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl Format {
+    /// The format of `path` by its extension: `csv`, `jsonl`/`ndjson` or `parquet`.
+    pub fn from_path(path: &Path) -> Result<Self, CKANError> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("csv") => Ok(Self::Csv),
+            Some("jsonl" | "ndjson") => Ok(Self::JsonLines),
+            #[cfg(feature = "parquet")]
+            Some("parquet") => Ok(Self::Parquet),
+            _ => Err(CKANError::Unsupported(format!(
+                "can not load {}, expected a .csv, .jsonl or .parquet file",
+                path.display()
+            ))),
+        }
+    }
+}
+
+type Records = Box<dyn Iterator<Item = Result<Map<String, Value>, CKANError>> + Send>;
+
+/// The records in the file at `path`, and its column names if the format has a header.
+fn read_records(path: &Path, format: Format) -> Result<(Vec<String>, Records), CKANError> {
+    match format {
+        Format::Csv => {
+            let mut reader = csv::Reader::from_path(path)?;
+            let headers = reader.headers()?.clone();
+            let columns = headers.iter().map(str::to_string).collect();
+            let records = reader.into_records().map(move |record| {
+                Ok(headers
+                    .iter()
+                    .zip(record?.iter())
+                    .map(|(header, value)| {
+                        // An empty cell is a missing value, not an empty string.
+                        let value = match value {
+                            "" => Value::Null,
+                            value => Value::String(value.to_string()),
+                        };
+                        (header.to_string(), value)
+                    })
+                    .collect())
+            });
+            Ok((columns, Box::new(records)))
+        }
+        Format::JsonLines => {
+            let lines = std::io::BufReader::new(std::fs::File::open(path)?).lines();
+            let records = lines.filter_map(|line| match line {
+                Ok(line) if line.trim().is_empty() => None,
+                // Fails with `SerdeJsonError` for lines that are not a JSON object.
+                Ok(line) => Some(serde_json::from_str(&line).map_err(CKANError::from)),
+                Err(error) => Some(Err(error.into())),
+            });
+            Ok((vec![], Box::new(records)))
+        }
+        #[cfg(feature = "parquet")]
+        Format::Parquet => {
+            use parquet::file::reader::{FileReader, SerializedFileReader};
+            let reader = SerializedFileReader::try_from(std::fs::File::open(path)?)?;
+            let columns = reader
+                .metadata()
+                .file_metadata()
+                .schema_descr()
+                .root_schema()
+                .get_fields()
+                .iter()
+                .map(|field| field.name().to_string())
+                .collect();
+            let records = reader.into_iter().map(|row| match row?.to_json_value() {
+                Value::Object(record) => Ok(record),
+                other => Ok(Map::from_iter([("value".to_string(), other)])),
+            });
+            Ok((columns, Box::new(records)))
+        }
+    }
+}
+
+/// The narrowest type that holds `value`, or `None` for a missing value.
+fn value_type(value: &Value) -> Option<DatastoreType> {
+    match value {
+        Value::Null => None,
+        Value::Bool(_) => Some(DatastoreType::Bool),
+        Value::Number(number) => Some(match number.as_i64() {
+            Some(number) if i32::try_from(number).is_ok() => DatastoreType::Int,
+            Some(_) => DatastoreType::BigInt,
+            None => DatastoreType::Numeric,
+        }),
+        Value::String(text) => Some(match text.as_str() {
+            "true" | "false" | "TRUE" | "FALSE" | "True" | "False" => DatastoreType::Bool,
+            text if NUMBER.is_match(text) => match text.parse::<i64>() {
+                Ok(number) => value_type(&json!(number))?,
+                Err(_) => DatastoreType::Numeric,
+            },
+            text if DATE.is_match(text) => DatastoreType::Date,
+            text if TIMESTAMP.is_match(text) => DatastoreType::Timestamp,
+            _ => DatastoreType::Text,
+        }),
+        Value::Array(_) | Value::Object(_) => Some(DatastoreType::Json),
+    }
+}
+
+/// The narrowest type that holds values of both `a` and `b`.
+fn widen(a: DatastoreType, b: DatastoreType) -> DatastoreType {
+    use DatastoreType::*;
+    match (a, b) {
+        (a, b) if a == b => a,
+        (Int | BigInt, Int | BigInt) => BigInt,
+        (Int | BigInt | Numeric, Int | BigInt | Numeric) => Numeric,
+        (Date | Timestamp, Date | Timestamp) => Timestamp,
+        (Json, _) | (_, Json) => Json,
+        _ => Text,
+    }
+}
+
+/// Infer the fields of a table holding `records`, in the order they first appear. Fields that
+/// are always missing are `text`.
+pub fn infer_fields<'a>(records: impl IntoIterator<Item = &'a Map<String, Value>>) -> Vec<Field> {
+    let mut fields: Vec<(String, Option<DatastoreType>)> = vec![];
+    for record in records {
+        for (id, value) in record {
+            let index = match fields.iter().position(|(field, _)| field == id) {
+                Some(index) => index,
+                None => {
+                    fields.push((id.clone(), None));
+                    fields.len() - 1
+                }
+            };
+            let field = &mut fields[index].1;
+            *field = match (field.take(), value_type(value)) {
+                (Some(a), Some(b)) => Some(widen(a, b)),
+                (a, b) => a.or(b),
+            };
+        }
+    }
+    fields
+        .into_iter()
+        .map(|(id, _type)| Field::new(id, _type.unwrap_or(DatastoreType::Text)))
+        .collect()
+}
+
+/// Convert text values read from a CSV file to the JSON type matching their field.
+fn coerce(record: &mut Map<String, Value>, fields: &[Field]) {
+    for field in fields {
+        let Some(Value::String(text)) = record.get(&field.id) else {
+            continue;
+        };
+        let value = match field._type {
+            DatastoreType::Int | DatastoreType::BigInt => text.parse::<i64>().ok().map(Value::from),
+            // PostgreSQL parses numeric text itself, without the precision lost to an f64.
+            DatastoreType::Float => text.parse::<f64>().ok().map(Value::from),
+            DatastoreType::Bool => text.to_lowercase().parse::<bool>().ok().map(Value::from),
+            DatastoreType::Json => serde_json::from_str(text).ok(),
+            _ => None,
+        };
+        if let Some(value) = value {
+            record.insert(field.id.clone(), value);
+        }
+    }
+}
+
+/// Emitted every time a batch is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadProgress {
+    /// Rows written so far, including skipped rows.
+    pub rows: usize,
+    pub batches: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadReport {
+    /// Rows written, including skipped rows.
+    pub rows: usize,
+    pub batches: usize,
+    /// The fields of the table, as given or inferred.
+    pub fields: Vec<Field>,
+}
+
+/// Load the file at `path` into the DataStore table of `resource_id`, through `ckan`.
+///
+/// - `format` defaults to the one matching the file extension.
+/// - `fields` are inferred from the first 1000 rows if not given.
+/// - Rows are upserted on `primary_key` if given, and inserted otherwise.
+/// - `batch_size` (10 000 by default) rows are sent per request, with `concurrency` (1 by
+///   default) requests in flight.
+///
+/// The table is created first unless `skip_rows` is set. If a batch fails the load stops
+/// with `CKANError::LoadInterrupted`, holding the number of rows written before it; pass it
+/// as `skip_rows` to resume. With more than one request in flight, batches after the failed
+/// one may have been written too, so resuming without a primary key can duplicate them.
+#[builder(on(String, into))]
+pub async fn load(
+    #[builder(start_fn)] ckan: &CKAN,
+    resource_id: String,
+    path: PathBuf,
+    format: Option<Format>,
+    fields: Option<Vec<Field>>,
+    primary_key: Option<Vec<String>>,
+    batch_size: Option<usize>,
+    concurrency: Option<usize>,
+    skip_rows: Option<usize>,
+    on_progress: Option<&(dyn Fn(&LoadProgress) + Sync)>,
+) -> Result<LoadReport, CKANError> {
+    ckan.require_extension("datastore", "datastore_create")
+        .await?;
+    let format = format.map_or_else(|| Format::from_path(&path), Ok)?;
+    let (columns, mut records) = read_records(&path, format)?;
+    let sample = records
+        .by_ref()
+        .take(SAMPLE_SIZE)
+        .collect::<Result<Vec<_>, _>>()?;
+    let fields = fields.unwrap_or_else(|| {
+        let mut fields = infer_fields(&sample);
+        // Keep the column order of the file, records are not ordered.
+        fields.sort_by_key(|field| columns.iter().position(|column| *column == field.id));
+        fields
+    });
+    let skip_rows = skip_rows.unwrap_or(0);
+    if skip_rows == 0 {
+        action_result(
+            ckan.datastore_create()
+                .resource_id(&resource_id)
+                .force(true)
+                .fields(fields.iter().map(|field| json!(field)).collect())
+                .maybe_primary_key(primary_key.as_ref().map(|key| json!(key)))
+                .call()
+                .await?,
+        )?;
+    }
+
+    let method = match primary_key {
+        Some(_) => "upsert",
+        None => "insert",
+    };
+    let batch_size = batch_size.unwrap_or(10_000).max(1);
+    let mut rows = sample.into_iter().map(Ok).chain(records).skip(skip_rows);
+    let batches = std::iter::from_fn(|| {
+        let batch = rows
+            .by_ref()
+            .take(batch_size)
+            .map(|record| {
+                let mut record = record?;
+                coerce(&mut record, &fields);
+                Ok(Value::Object(record))
+            })
+            .collect::<Result<Vec<_>, CKANError>>();
+        match batch {
+            Ok(batch) if batch.is_empty() => None,
+            batch => Some(batch),
+        }
+    });
+    let mut uploads = stream::iter(batches)
+        .map(|batch| async {
+            let batch = batch?;
+            let len = batch.len();
+            let response = ckan
+                .datastore_upsert()
+                .resource_id(&resource_id)
+                .force(true)
+                .records(batch)
+                .method(method)
+                .call()
+                .await?;
+            action_result(response)?;
+            Ok::<_, CKANError>(len)
+        })
+        .buffered(concurrency.unwrap_or(1).max(1));
+
+    let mut progress = LoadProgress {
+        rows: skip_rows,
+        batches: 0,
+    };
+    while let Some(result) = uploads.next().await {
+        match result {
+            Ok(len) => {
+                progress.rows += len;
+                progress.batches += 1;
+                if let Some(on_progress) = on_progress {
+                    on_progress(&progress);
+                }
+            }
+            Err(error) => {
+                return Err(CKANError::LoadInterrupted {
+                    rows: progress.rows,
+                    source: Box::new(error),
+                });
+            }
+        }
+    }
+    drop(uploads);
+    Ok(LoadReport {
+        rows: progress.rows,
+        batches: progress.batches,
+        fields,
+    })
+}
+
+#[derive(Deserialize)]
+struct Page {
+    #[serde(default)]
+    fields: Vec<Field>,
+    #[serde(default)]
+    records: Vec<Map<String, Value>>,
+    total: Option<usize>,
+}
+
+/// All the rows of a DataStore search, with the types of their fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub fields: Vec<Field>,
+    pub records: Vec<Map<String, Value>>,
+}
+
+impl Table {
+    /// Write the table as CSV, with the field ids as header. Missing values are empty cells and
+    /// `json` values are written as JSON.
+    pub fn write_csv<W: std::io::Write>(&self, writer: W) -> Result<(), CKANError> {
+        let mut writer = csv::Writer::from_writer(writer);
+        writer.write_record(self.fields.iter().map(|field| &field.id))?;
+        for record in &self.records {
+            writer.write_record(self.fields.iter().map(|field| {
+                match record.get(&field.id).unwrap_or(&Value::Null) {
+                    Value::Null => String::new(),
+                    Value::String(text) => text.clone(),
+                    value => value.to_string(),
+                }
+            }))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// The table as an Arrow record batch. Integer fields become `Int64`, `numeric` and `float`
+    /// fields `Float64`, `date` fields `Date32`, `timestamp` fields `Timestamp(Microsecond)` and
+    /// everything else, including `json`, `Utf8`.
+    #[cfg(feature = "arrow")]
+    pub fn to_record_batch(&self) -> Result<arrow_array::RecordBatch, CKANError> {
+        use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
+        use arrow_schema::{DataType, Field as ArrowField, Schema, TimeUnit};
+        use std::sync::Arc;
+
+        let number = |value: &Value| match value {
+            Value::String(text) => text.parse::<f64>().ok(),
+            value => value.as_f64(),
+        };
+        // Not through `f64`, which loses precision above 2^53.
+        let integer = |value: &Value| match value {
+            Value::String(text) => text.parse::<i64>().ok(),
+            value => value.as_i64(),
+        };
+        let mut columns: Vec<ArrayRef> = vec![];
+        let mut schema = vec![];
+        for field in &self.fields {
+            let values = self
+                .records
+                .iter()
+                .map(|record| record.get(&field.id).unwrap_or(&Value::Null));
+            let text: StringArray = values
+                .clone()
+                .map(|value| match value {
+                    Value::Null => None,
+                    Value::String(text) => Some(text.clone()),
+                    value => Some(value.to_string()),
+                })
+                .collect();
+            let column: ArrayRef = match field._type.as_str() {
+                "int" | "int2" | "int4" | "int8" | "bigint" | "integer" | "smallint" => {
+                    Arc::new(values.map(integer).collect::<Int64Array>())
+                }
+                "numeric" | "float" | "float4" | "float8" | "double precision" | "real" => {
+                    Arc::new(values.map(number).collect::<Float64Array>())
+                }
+                "bool" | "boolean" => Arc::new(
+                    values
+                        .map(|value| match value {
+                            Value::String(text) => text.to_lowercase().parse().ok(),
+                            value => value.as_bool(),
+                        })
+                        .collect::<BooleanArray>(),
+                ),
+                "date" => arrow_cast::cast(&text, &DataType::Date32)?,
+                "timestamp" => {
+                    arrow_cast::cast(&text, &DataType::Timestamp(TimeUnit::Microsecond, None))?
+                }
+                _ => Arc::new(text),
+            };
+            schema.push(ArrowField::new(&field.id, column.data_type().clone(), true));
+            columns.push(column);
+        }
+        Ok(arrow_array::RecordBatch::try_new(
+            Arc::new(Schema::new(schema)),
+            columns,
+        )?)
+    }
+
+    /// Write the table as a Parquet file, with the types of [`Table::to_record_batch`].
+    #[cfg(feature = "parquet")]
+    pub fn write_parquet<W: std::io::Write + Send>(&self, writer: W) -> Result<(), CKANError> {
+        let batch = self.to_record_batch()?;
+        let mut writer = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+}
+
+impl CKAN {
+    /// The pages of a `datastore_search` with `body`, `limit` rows at a time, sorted by `_id`
+    /// unless `body` has a `sort`. Every page is searched with `body` at the next offset; the
+    /// `_links.next` of the server is not used, as it lacks the parameters of a POST.
+    fn datastore_pages(
+        &self,
+        mut body: Value,
+        limit: usize,
+    ) -> impl futures::Stream<Item = Result<Page, CKANError>> + '_ {
+        if body.get("sort").is_none() {
+            // Without a sort order, rows can move between pages.
+            body["sort"] = json!("_id");
+        }
+        body["limit"] = json!(limit);
+        stream::unfold(Some(0), move |offset: Option<usize>| {
+            let mut body = body.clone();
+            async move {
+                let offset = offset?;
+                body["offset"] = json!(offset);
+                let page = self
+                    .call_action("datastore_search", body)
+                    .await
+                    .and_then(|page| Ok(serde_json::from_value::<Page>(page)?));
+                let page = match page {
+                    Ok(page) => page,
+                    Err(error) => return Some((Err(error), None)),
+                };
+                let end = offset + page.records.len();
+                // The server caps `limit`, so a short page is only the last one if there is no
+                // total telling otherwise.
+                let more = !page.records.is_empty()
+                    && page
+                        .total
+                        .map_or(page.records.len() == limit, |total| end < total);
+                Some((Ok(page), more.then_some(end)))
+            }
+        })
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// Stream the rows of a DataStore search, deserialized into `T`, fetching `page_size` (1000 by
+    /// default) rows at a time.
+    ///
+    /// ```no_run
+    /// # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+    /// use futures::TryStreamExt;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Measurement {
+    ///     station: String,
+    ///     value: f64,
+    /// }
+    ///
+    /// let mut rows = ckan
+    ///     .datastore_records::<Measurement>()
+    ///     .resource_id("0b7f5d5e-8c1a-4c1e-9a44-1c3f2b0e6f7a")
+    ///     .filters(serde_json::json!({ "station": "north" }))
+    ///     .call();
+    /// while let Some(row) = rows.try_next().await? {
+    ///     println!("{}: {}", row.station, row.value);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[builder(on(String, into))]
+    pub fn datastore_records<'a, T: serde::de::DeserializeOwned + Send + 'a>(
+        &'a self,
+        resource_id: String,
+        filters: Option<Value>,
+        q: Option<String>,
+        fields: Option<Vec<String>>,
+        sort: Option<String>,
+        page_size: Option<usize>,
+    ) -> futures::stream::BoxStream<'a, Result<T, CKANError>> {
+        let body = search_body(resource_id, filters, q, fields, sort);
+        self.datastore_pages(body, page_size.unwrap_or(1000).max(1))
+            .flat_map(|page| {
+                let rows: Vec<Result<T, CKANError>> = match page {
+                    Ok(page) => page
+                        .records
+                        .into_iter()
+                        .map(|record| Ok(serde_json::from_value(Value::Object(record))?))
+                        .collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                stream::iter(rows)
+            })
+            .boxed()
+    }
+
+    /// Fetch all the rows of a DataStore search with their field types, `page_size` (1000 by
+    /// default) rows at a time, e.g. to export them with [`Table::write_csv`].
+    #[builder(on(String, into))]
+    pub async fn datastore_table(
+        &self,
+        resource_id: String,
+        filters: Option<Value>,
+        q: Option<String>,
+        fields: Option<Vec<String>>,
+        sort: Option<String>,
+        page_size: Option<usize>,
+    ) -> Result<Table, CKANError> {
+        let body = search_body(resource_id, filters, q, fields, sort);
+        let mut pages =
+            std::pin::pin!(self.datastore_pages(body, page_size.unwrap_or(1000).max(1)));
+        let mut table = Table::default();
+        while let Some(page) = pages.next().await {
+            let page = page?;
+            if table.fields.is_empty() {
+                table.fields = page.fields;
+            }
+            table.records.extend(page.records);
+        }
+        Ok(table)
+    }
+
+    /// Stream the rows of a DataStore search as Arrow record batches, one per page of
+    /// `page_size` (1000 by default) rows, with the types of [`Table::to_record_batch`]. Unlike
+    /// `datastore_table`, only one page is held in memory at a time.
+    #[cfg(feature = "arrow")]
+    #[builder(on(String, into))]
+    pub fn datastore_record_batches<'a>(
+        &'a self,
+        resource_id: String,
+        filters: Option<Value>,
+        q: Option<String>,
+        fields: Option<Vec<String>>,
+        sort: Option<String>,
+        page_size: Option<usize>,
+    ) -> futures::stream::BoxStream<'a, Result<arrow_array::RecordBatch, CKANError>> {
+        let body = search_body(resource_id, filters, q, fields, sort);
+        self.datastore_pages(body, page_size.unwrap_or(1000).max(1))
+            .scan(vec![], |fields: &mut Vec<Field>, page| {
+                let batch = page.and_then(|page| {
+                    // Every batch has the schema of the first page.
+                    if fields.is_empty() {
+                        *fields = page.fields;
+                    }
+                    Table {
+                        fields: fields.clone(),
+                        records: page.records,
+                    }
+                    .to_record_batch()
+                });
+                std::future::ready(Some(batch))
+            })
+            .boxed()
+    }
+}
+
+/// The body of a `datastore_search`, without the parameters that are not given.
+fn search_body(
+    resource_id: String,
+    filters: Option<Value>,
+    q: Option<String>,
+    fields: Option<Vec<String>>,
+    sort: Option<String>,
+) -> Value {
+    let mut body = json!({
+        "resource_id": resource_id,
+        "filters": filters,
+        "q": q,
+        "fields": fields,
+        "sort": sort,
+    });
+    if let Value::Object(body) = &mut body {
+        body.retain(|_, value| !value.is_null());
+    }
+    body
+}
@@ -0,0 +1,59 @@
+//! Dry-run mode: record mutating actions instead of sending them.
+//!
+//! Build a `CKAN` with `.dry_run(true)` and every action that is not a read (see
+//! [`is_read_action`]) is appended to an in-memory journal and answered with a synthetic
+//! `{"success": true, "result": <body>, "dry_run": true}` response. Read actions still reach the
+//! server, so scripts that look things up before changing them behave as they would for real.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), ckanaction::CKANError> {
+//! let ckan = ckanaction::CKAN::builder()
+//!     .url("https://demo.ckan.org")
+//!     .dry_run(true)
+//!     .build();
+//! ckan.package_delete().id("my-dataset").call().await?;
+//! for entry in ckan.dry_run_journal() {
+//!     println!("{} {}", entry.endpoint, entry.body.unwrap_or_default());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::actions::READ_ACTIONS;
+use serde_json::json;
+use std::{path::PathBuf, sync::Mutex};
+
+/// Whether `action` only reads data, as listed in [`READ_ACTIONS`]. Everything else is treated
+/// as mutating in dry-run mode.
+pub fn is_read_action(action: &str) -> bool {
+    READ_ACTIONS.contains(&action)
+}
+
+/// A mutating request that was recorded instead of being sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunEntry {
+    pub action: String,
+    pub endpoint: String,
+    pub body: Option<serde_json::Value>,
+    pub upload: Option<PathBuf>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Journal(Mutex<Vec<DryRunEntry>>);
+
+impl Journal {
+    /// Record `entry` and return the synthetic response sent back to the caller.
+    pub(crate) fn record(&self, entry: DryRunEntry) -> serde_json::Value {
+        let response = json!({
+            "success": true,
+            "dry_run": true,
+            "result": entry.body.clone().unwrap_or_else(|| json!({})),
+        });
+        self.0.lock().unwrap().push(entry);
+        response
+    }
+
+    pub(crate) fn entries(&self) -> Vec<DryRunEntry> {
+        self.0.lock().unwrap().clone()
+    }
+}
@@ -0,0 +1,1999 @@
+//! CKAN actions, generated from `docs/lib/openapi.yml` by `cargo xtask openapi`. Do not edit by hand.
+
+use crate::{
+    CKAN, CKANError, hashmap_to_json, opsert,
+    params::{
+        GroupSort, HarvestFrequency, HarvestJobStatus, ObjectType, OrderBy, OwnerType, PackageSort,
+        Permission, Role, State, UpsertMethod, ViewType,
+    },
+};
+use bon::bon;
+use serde_json::json;
+use std::{collections::HashMap, path::PathBuf};
+
+#[bon]
+impl CKAN {
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_list
+    #[builder(on(String, into))]
+    pub async fn package_list(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_list", limit, offset)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.current_package_list_with_resources
+    #[builder(on(String, into))]
+    pub async fn current_package_list_with_resources(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        page: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "current_package_list_with_resources",
+            limit,
+            offset,
+            page
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.member_list
+    #[builder(on(String, into))]
+    pub async fn member_list(
+        &self,
+        id: String,
+        #[builder(into)] object_type: Option<ObjectType>,
+        #[builder(into)] capacity: Option<Role>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "member_list", (json id), object_type, capacity)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_collaborator_list
+    #[builder(on(String, into))]
+    pub async fn package_collaborator_list(
+        &self,
+        id: String,
+        #[builder(into)] capacity: Option<Role>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_collaborator_list", (json id), capacity)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_collaborator_list_for_user
+    #[builder(on(String, into))]
+    pub async fn package_collaborator_list_for_user(
+        &self,
+        id: String,
+        #[builder(into)] capacity: Option<Role>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_collaborator_list_for_user", (json id), capacity)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_list
+    #[builder(on(String, into))]
+    pub async fn group_list(
+        &self,
+        _type: Option<String>,
+        #[builder(into)] sort: Option<GroupSort>,
+        #[builder(into)] order_by: Option<OrderBy>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        groups: Option<Vec<String>>,
+        all_fields: Option<bool>,
+        include_dataset_count: Option<bool>,
+        include_extras: Option<bool>,
+        include_tags: Option<bool>,
+        include_groups: Option<bool>,
+        include_users: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "group_list",
+            ("type", _type),
+            sort,
+            order_by,
+            limit,
+            offset,
+            groups,
+            all_fields,
+            include_dataset_count,
+            include_extras,
+            include_tags,
+            include_groups,
+            include_users
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_list
+    #[builder(on(String, into))]
+    pub async fn organization_list(
+        &self,
+        _type: Option<String>,
+        #[builder(into)] sort: Option<GroupSort>,
+        #[builder(into)] order_by: Option<OrderBy>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+        organizations: Option<Vec<String>>,
+        all_fields: Option<bool>,
+        include_dataset_count: Option<bool>,
+        include_extras: Option<bool>,
+        include_tags: Option<bool>,
+        include_groups: Option<bool>,
+        include_users: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "organization_list",
+            ("type", _type),
+            sort,
+            order_by,
+            limit,
+            offset,
+            organizations,
+            all_fields,
+            include_dataset_count,
+            include_extras,
+            include_tags,
+            include_groups,
+            include_users
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_list_authz
+    #[builder(on(String, into))]
+    pub async fn group_list_authz(
+        &self,
+        available_only: Option<bool>,
+        am_member: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_list_authz", available_only, am_member)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_list_for_user
+    #[builder(on(String, into))]
+    pub async fn organization_list_for_user(
+        &self,
+        id: Option<String>,
+        #[builder(into)] permission: Option<Permission>,
+        include_dataset_count: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "organization_list_for_user",
+            id,
+            permission,
+            include_dataset_count
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.license_list
+    #[builder(on(String, into))]
+    pub async fn license_list(&self) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "license_list")
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_list
+    #[builder(on(String, into))]
+    pub async fn tag_list(
+        &self,
+        query: Option<String>,
+        vocabulary_id: Option<String>,
+        all_fields: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_list", query, vocabulary_id, all_fields)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_list
+    #[builder(on(String, into))]
+    pub async fn user_list(
+        &self,
+        q: Option<String>,
+        email: Option<String>,
+        #[builder(into)] order_by: Option<OrderBy>,
+        all_fields: Option<bool>,
+        include_site_user: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "user_list",
+            q,
+            email,
+            order_by,
+            all_fields,
+            include_site_user
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_relationships_list
+    #[builder(on(String, into))]
+    pub async fn package_relationships_list(
+        &self,
+        id: String,
+        id2: String,
+        rel: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_relationships_list", (json id), (json id2), rel)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_show
+    #[builder(on(String, into))]
+    pub async fn package_show(
+        &self,
+        id: String,
+        use_default_schema: Option<bool>,
+        include_plugin_data: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_show", (json id), use_default_schema, include_plugin_data)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_show
+    #[builder(on(String, into))]
+    pub async fn resource_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_show", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_view_show
+    #[builder(on(String, into))]
+    pub async fn resource_view_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_show", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_view_list
+    #[builder(on(String, into))]
+    pub async fn resource_view_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_show
+    #[builder(on(String, into))]
+    pub async fn group_show(
+        &self,
+        id: String,
+        include_datasets: Option<bool>,
+        include_dataset_count: Option<bool>,
+        include_extras: Option<bool>,
+        include_users: Option<bool>,
+        include_groups: Option<bool>,
+        include_tags: Option<bool>,
+        include_followers: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_show", (json id), include_datasets, include_dataset_count, include_extras, include_users, include_groups, include_tags, include_followers)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_show
+    #[builder(on(String, into))]
+    pub async fn organization_show(
+        &self,
+        id: String,
+        include_datasets: Option<bool>,
+        include_dataset_count: Option<bool>,
+        include_extras: Option<bool>,
+        include_users: Option<bool>,
+        include_groups: Option<bool>,
+        include_tags: Option<bool>,
+        include_followers: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_show", (json id), include_datasets, include_dataset_count, include_extras, include_users, include_groups, include_tags, include_followers)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_package_show
+    #[builder(on(String, into))]
+    pub async fn group_package_show(
+        &self,
+        id: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_package_show", (json id), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_show
+    #[builder(on(String, into))]
+    pub async fn tag_show(
+        &self,
+        id: String,
+        vocabulary_id: Option<String>,
+        include_datasets: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_show", (json id), vocabulary_id, include_datasets)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_show
+    #[builder(on(String, into))]
+    pub async fn user_show(
+        &self,
+        id: String,
+        include_datasets: Option<bool>,
+        include_num_followers: Option<bool>,
+        include_password_hash: Option<bool>,
+        include_plugin_extras: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_show", (json id), include_datasets, include_num_followers, include_password_hash, include_plugin_extras)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_autocomplete
+    #[builder(on(String, into))]
+    pub async fn package_autocomplete(
+        &self,
+        q: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_autocomplete", (json q), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.format_autocomplete
+    #[builder(on(String, into))]
+    pub async fn format_autocomplete(
+        &self,
+        q: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "format_autocomplete", (json q), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_autocomplete
+    #[builder(on(String, into))]
+    pub async fn user_autocomplete(
+        &self,
+        q: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_autocomplete", (json q), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_autocomplete
+    #[builder(on(String, into))]
+    pub async fn group_autocomplete(
+        &self,
+        q: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_autocomplete", (json q), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_autocomplete
+    #[builder(on(String, into))]
+    pub async fn organization_autocomplete(
+        &self,
+        q: String,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_autocomplete", (json q), limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.package_search
+    #[builder(on(String, into))]
+    pub async fn package_search(
+        &self,
+        q: Option<String>,
+        fq: Option<String>,
+        fq_list: Option<Vec<String>>,
+        #[builder(into)] sort: Option<PackageSort>,
+        rows: Option<i32>,
+        start: Option<i32>,
+        facet: Option<String>,
+        facet_mincount: Option<i32>,
+        facet_limit: Option<i32>,
+        facet_field: Option<Vec<String>>,
+        include_drafts: Option<bool>,
+        include_deleted: Option<bool>,
+        include_private: Option<bool>,
+        use_default_schema: Option<bool>,
+        #[cfg(feature = "spatial")]
+        #[builder(into)]
+        ext_bbox: Option<crate::spatial::BBox>,
+        #[cfg(feature = "spatial")]
+        #[builder(into)]
+        ext_prev_extent: Option<crate::spatial::BBox>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_search", q, fq, fq_list, sort, rows, start, facet, ("facet.mincount", facet_mincount), ("facet.limit", facet_limit), ("facet.field", facet_field), include_drafts, include_deleted, include_private, use_default_schema, (cfg "spatial", (extra ext_bbox)), (cfg "spatial", (extra ext_prev_extent)))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.resource_search
+    #[builder(on(String, into))]
+    pub async fn resource_search<T: serde::Serialize>(
+        &self,
+        query: Option<T>,
+        #[builder(into)] order_by: Option<OrderBy>,
+        offset: Option<i32>,
+        limit: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_search", query, order_by, offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_search
+    #[builder(on(String, into))]
+    pub async fn tag_search<T: serde::Serialize>(
+        &self,
+        query: Option<T>,
+        vocabulary_id: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_search", query, vocabulary_id, limit, offset)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.tag_autocomplete
+    #[builder(on(String, into))]
+    pub async fn tag_autocomplete(
+        &self,
+        query: String,
+        vocabulary_id: Option<String>,
+        limit: Option<i32>,
+        offset: Option<i32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_autocomplete", (json query), vocabulary_id, limit, offset)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.task_status_show
+    #[builder(on(String, into))]
+    pub async fn task_status_show(
+        &self,
+        id: Option<String>,
+        entity_id: Option<String>,
+        task_type: Option<String>,
+        key: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "task_status_show", id, entity_id, task_type, key)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.term_translation_show
+    #[builder(on(String, into))]
+    pub async fn term_translation_show(
+        &self,
+        terms: Option<Vec<String>>,
+        lang_codes: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "term_translation_show", terms, lang_codes)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.get_site_user
+    #[builder(on(String, into))]
+    pub async fn get_site_user(
+        &self,
+        defer_commit: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "get_site_user", defer_commit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.status_show
+    pub async fn status_show(&self) -> Result<serde_json::Value, CKANError> {
+        let endpoint = self.url.clone() + "/api/3/action/status_show";
+        Self::get(self, endpoint).await
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.vocabulary_list
+    pub async fn vocabulary_list(&self) -> Result<serde_json::Value, CKANError> {
+        let endpoint = self.url.clone() + "/api/3/action/vocabulary_list";
+        Self::get(self, endpoint).await
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.vocabulary_show
+    #[builder(on(String, into))]
+    pub async fn vocabulary_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "vocabulary_show", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_follower_count
+    #[builder(on(String, into))]
+    pub async fn user_follower_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_follower_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_follower_count
+    #[builder(on(String, into))]
+    pub async fn dataset_follower_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dataset_follower_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_follower_count
+    #[builder(on(String, into))]
+    pub async fn group_follower_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_follower_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_follower_count
+    #[builder(on(String, into))]
+    pub async fn organization_follower_count(
+        &self,
+        id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_follower_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_follower_list
+    #[builder(on(String, into))]
+    pub async fn user_follower_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_follower_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_follower_list
+    #[builder(on(String, into))]
+    pub async fn dataset_follower_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dataset_follower_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_follower_list
+    #[builder(on(String, into))]
+    pub async fn group_follower_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_follower_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_follower_list
+    #[builder(on(String, into))]
+    pub async fn organization_follower_list(
+        &self,
+        id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_follower_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_user
+    #[builder(on(String, into))]
+    pub async fn am_following_user(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "am_following_user", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_dataset
+    #[builder(on(String, into))]
+    pub async fn am_following_dataset(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "am_following_dataset", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.am_following_group
+    #[builder(on(String, into))]
+    pub async fn am_following_group(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "am_following_group", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.followee_count
+    #[builder(on(String, into))]
+    pub async fn followee_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "followee_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_followee_count
+    #[builder(on(String, into))]
+    pub async fn user_followee_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_followee_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_followee_count
+    #[builder(on(String, into))]
+    pub async fn dataset_followee_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dataset_followee_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_followee_count
+    #[builder(on(String, into))]
+    pub async fn group_followee_count(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_followee_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_followee_count
+    #[builder(on(String, into))]
+    pub async fn organization_followee_count(
+        &self,
+        id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_followee_count", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.followee_list
+    #[builder(on(String, into))]
+    pub async fn followee_list(
+        &self,
+        id: String,
+        q: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "followee_list", (json id), q)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.user_followee_list
+    #[builder(on(String, into))]
+    pub async fn user_followee_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_followee_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.dataset_followee_list
+    #[builder(on(String, into))]
+    pub async fn dataset_followee_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dataset_followee_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.group_followee_list
+    #[builder(on(String, into))]
+    pub async fn group_followee_list(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_followee_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.organization_followee_list
+    #[builder(on(String, into))]
+    pub async fn organization_followee_list(
+        &self,
+        id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_followee_list", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.member_roles_list
+    #[builder(on(String, into))]
+    pub async fn member_roles_list(
+        &self,
+        group_type: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "member_roles_list", group_type)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.help_show
+    #[builder(on(String, into))]
+    pub async fn help_show(&self, name: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "help_show", (json name))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.config_option_show
+    #[builder(on(String, into))]
+    pub async fn config_option_show(&self, key: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "config_option_show", (json key))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.config_option_list
+    pub async fn config_option_list(&self) -> Result<serde_json::Value, CKANError> {
+        let endpoint = self.url.clone() + "/api/3/action/config_option_list";
+        Self::get(self, endpoint).await
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.job_list
+    #[builder(on(String, into))]
+    pub async fn job_list(
+        &self,
+        queues: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "job_list", queues)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.job_show
+    #[builder(on(String, into))]
+    pub async fn job_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "job_show", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.api_token_list
+    #[builder(on(String, into))]
+    pub async fn api_token_list(&self, user_id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "api_token_list", (json user_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.get.site_read
+    pub async fn site_read(&self) -> Result<serde_json::Value, CKANError> {
+        let endpoint = self.url.clone() + "/api/3/action/site_read";
+        Self::get(self, endpoint).await
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_show
+    #[builder(on(String, into))]
+    pub async fn activity_show(
+        &self,
+        id: String,
+        include_data: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "activity_show", (json id), include_data)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_data_show
+    #[builder(on(String, into))]
+    pub async fn activity_data_show(
+        &self,
+        id: String,
+        object_type: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "activity_data_show", (json id), object_type)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.activity_diff
+    #[builder(on(String, into))]
+    pub async fn activity_diff(
+        &self,
+        id: String,
+        object_type: String,
+        diff_type: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "activity_diff", (json id), (json object_type), diff_type)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.package_activity_list
+    #[builder(on(String, into))]
+    pub async fn package_activity_list(
+        &self,
+        id: String,
+        include_hidden_activity: Option<bool>,
+        activity_types: Option<Vec<String>>,
+        exclude_activity_types: Option<Vec<String>>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_activity_list", (json id), include_hidden_activity, activity_types, exclude_activity_types, offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.group_activity_list
+    #[builder(on(String, into))]
+    pub async fn group_activity_list(
+        &self,
+        id: String,
+        include_hidden_activity: Option<bool>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_activity_list", (json id), include_hidden_activity, offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.organization_activity_list
+    #[builder(on(String, into))]
+    pub async fn organization_activity_list(
+        &self,
+        id: String,
+        include_hidden_activity: Option<bool>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_activity_list", (json id), include_hidden_activity, offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.user_activity_list
+    #[builder(on(String, into))]
+    pub async fn user_activity_list(
+        &self,
+        id: String,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_activity_list", (json id), offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.recently_changed_packages_activity_list
+    #[builder(on(String, into))]
+    pub async fn recently_changed_packages_activity_list(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "recently_changed_packages_activity_list",
+            offset,
+            limit
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.dashboard_activity_list
+    #[builder(on(String, into))]
+    pub async fn dashboard_activity_list(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dashboard_activity_list", offset, limit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckanext.activity.logic.action.dashboard_new_activities_count
+    pub async fn dashboard_new_activities_count(&self) -> Result<serde_json::Value, CKANError> {
+        let endpoint = self.url.clone() + "/api/3/action/dashboard_new_activities_count";
+        Self::get(self, endpoint).await
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_create
+    #[builder(on(String, into))]
+    pub async fn package_create(
+        &self,
+        name: String,
+        title: Option<String>,
+        private: bool,
+        author: Option<String>,
+        author_email: Option<String>,
+        maintainer: Option<String>,
+        maintainer_email: Option<String>,
+        license_id: Option<String>,
+        notes: Option<String>,
+        url: Option<String>,
+        version: Option<String>,
+        #[builder(into)] state: Option<State>,
+        _type: Option<String>,
+        resources: Option<Vec<serde_json::Value>>,
+        tags: Option<Vec<serde_json::Value>>,
+        extras: Option<Vec<serde_json::Value>>,
+        plugin_data: Option<serde_json::Value>,
+        relationships_as_object: Option<Vec<serde_json::Value>>,
+        relationships_as_subject: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        owner_org: Option<String>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_create", (json name), title, (json private), author, author_email, maintainer, maintainer_email, license_id, notes, url, version, state, ("type", _type), resources, tags, extras, plugin_data, relationships_as_object, relationships_as_subject, groups, owner_org, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_create
+    #[builder(on(String, into))]
+    pub async fn resource_create(
+        &self,
+        package_id: String,
+        url: Option<String>,
+        description: Option<String>,
+        format: Option<String>,
+        hash: Option<String>,
+        name: Option<String>,
+        resource_type: Option<String>,
+        mimetype: Option<String>,
+        mimetype_inner: Option<String>,
+        cache_url: Option<String>,
+        size: Option<i32>,
+        created: Option<String>,
+        last_modified: Option<String>,
+        cache_last_updated: Option<String>,
+        upload: Option<PathBuf>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_create", (json package_id), url, description, format, hash, name, resource_type, mimetype, mimetype_inner, cache_url, size, created, last_modified, cache_last_updated; (upload upload))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_view_create
+    #[builder(on(String, into))]
+    pub async fn resource_view_create(
+        &self,
+        resource_id: String,
+        title: String,
+        description: Option<String>,
+        #[builder(into)] view_type: ViewType,
+        config: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_create", (json resource_id), (json title), description, (json view_type), (extend config))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.resource_create_default_resource_views
+    #[builder(on(String, into))]
+    pub async fn resource_create_default_resource_views(
+        &self,
+        resource: serde_json::Value,
+        package: Option<serde_json::Value>,
+        create_datastore_views: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_create_default_resource_views", (json resource), package, create_datastore_views)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_create_default_resource_views
+    #[builder(on(String, into))]
+    pub async fn package_create_default_resource_views(
+        &self,
+        package: serde_json::Value,
+        create_datastore_views: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_create_default_resource_views", (json package), create_datastore_views)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_relationship_create
+    #[builder(on(String, into))]
+    pub async fn package_relationship_create(
+        &self,
+        subject: String,
+        object: String,
+        _type: String,
+        comment: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_relationship_create", (json subject), (json object), (json "type", _type), comment)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.member_create
+    #[builder(on(String, into))]
+    pub async fn member_create(
+        &self,
+        id: String,
+        object: String,
+        #[builder(into)] object_type: ObjectType,
+        #[builder(into)] capacity: Role,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "member_create", (json id), (json object), (json object_type), (json capacity))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.package_collaborator_create
+    #[builder(on(String, into))]
+    pub async fn package_collaborator_create(
+        &self,
+        id: String,
+        user_id: String,
+        #[builder(into)] capacity: Role,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_collaborator_create", (json id), (json user_id), (json capacity))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.group_create
+    #[builder(on(String, into))]
+    pub async fn group_create(
+        &self,
+        name: String,
+        id: Option<String>,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        _type: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_create", (json name), id, title, description, image_url, ("type", _type), state, approval_status, extras, packages, groups, users, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.organization_create
+    #[builder(on(String, into))]
+    pub async fn organization_create(
+        &self,
+        name: String,
+        id: Option<String>,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_create", (json name), id, title, description, image_url, state, approval_status, extras, packages, users, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.user_create
+    #[builder(on(String, into))]
+    pub async fn user_create(
+        &self,
+        name: String,
+        email: String,
+        password: String,
+        id: Option<String>,
+        fullname: Option<String>,
+        about: Option<String>,
+        image_url: Option<String>,
+        plugin_extras: Option<serde_json::Value>,
+        with_apitoken: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_create", (json name), (json email), (json password), id, fullname, about, image_url, plugin_extras, with_apitoken)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.user_invite
+    #[builder(on(String, into))]
+    pub async fn user_invite(
+        &self,
+        email: String,
+        group_id: String,
+        #[builder(into)] role: Role,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_invite", (json email), (json group_id), (json role))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.vocabulary_create
+    #[builder(on(String, into))]
+    pub async fn vocabulary_create(
+        &self,
+        name: String,
+        tags: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "vocabulary_create", (json name), (json tags))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.tag_create
+    #[builder(on(String, into))]
+    pub async fn tag_create(
+        &self,
+        name: String,
+        vocabulary_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_create", (json name), (json vocabulary_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_user
+    #[builder(on(String, into))]
+    pub async fn follow_user(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "follow_user", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_dataset
+    #[builder(on(String, into))]
+    pub async fn follow_dataset(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "follow_dataset", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.group_member_create
+    #[builder(on(String, into))]
+    pub async fn group_member_create(
+        &self,
+        id: String,
+        username: String,
+        #[builder(into)] role: Role,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_member_create", (json id), (json username), (json role))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.organization_member_create
+    #[builder(on(String, into))]
+    pub async fn organization_member_create(
+        &self,
+        id: String,
+        username: String,
+        #[builder(into)] role: Role,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_member_create", (json id), (json username), (json role))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.follow_group
+    #[builder(on(String, into))]
+    pub async fn follow_group(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "follow_group", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.create.api_token_create
+    #[builder(on(String, into))]
+    pub async fn api_token_create(
+        &self,
+        user: String,
+        name: String,
+        expires_in: Option<u32>,
+        unit: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "api_token_create", (json user), (json name), expires_in, unit)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_update
+    #[builder(on(String, into))]
+    pub async fn resource_update(
+        &self,
+        id: String,
+        package_id: String,
+        url: Option<String>,
+        description: Option<String>,
+        format: Option<String>,
+        hash: Option<String>,
+        name: Option<String>,
+        resource_type: Option<String>,
+        mimetype: Option<String>,
+        mimetype_inner: Option<String>,
+        cache_url: Option<String>,
+        size: Option<i32>,
+        created: Option<String>,
+        last_modified: Option<String>,
+        cache_last_updated: Option<String>,
+        upload: Option<PathBuf>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_update", (json id), (json package_id), url, description, format, hash, name, resource_type, mimetype, mimetype_inner, cache_url, size, created, last_modified, cache_last_updated; (upload upload))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_view_update
+    #[builder(on(String, into))]
+    pub async fn resource_view_update(
+        &self,
+        id: String,
+        resource_id: String,
+        title: String,
+        description: Option<String>,
+        #[builder(into)] view_type: ViewType,
+        config: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_update", (json id), (json resource_id), (json title), description, (json view_type), (extend config))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.resource_view_reorder
+    #[builder(on(String, into))]
+    pub async fn resource_view_reorder(
+        &self,
+        id: String,
+        order: Vec<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_reorder", (json id), (json order))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_update
+    #[builder(on(String, into))]
+    pub async fn package_update(
+        &self,
+        id: String,
+        name: String,
+        title: Option<String>,
+        private: bool,
+        author: Option<String>,
+        author_email: Option<String>,
+        maintainer: Option<String>,
+        maintainer_email: Option<String>,
+        license_id: Option<String>,
+        notes: Option<String>,
+        url: Option<String>,
+        version: Option<String>,
+        #[builder(into)] state: Option<State>,
+        _type: Option<String>,
+        resources: Option<Vec<serde_json::Value>>,
+        tags: Option<Vec<serde_json::Value>>,
+        extras: Option<Vec<serde_json::Value>>,
+        plugin_data: Option<serde_json::Value>,
+        relationships_as_object: Option<Vec<serde_json::Value>>,
+        relationships_as_subject: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        owner_org: Option<String>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_update", (json id), (json name), title, (json private), author, author_email, maintainer, maintainer_email, license_id, notes, url, version, state, ("type", _type), resources, tags, extras, plugin_data, relationships_as_object, relationships_as_subject, groups, owner_org, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_revise
+    #[builder(on(String, into))]
+    pub async fn package_revise(
+        &self,
+        _match: serde_json::Value,
+        filter: Option<Vec<String>>,
+        update: serde_json::Value,
+        include: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_revise", (json "match", _match), filter, (json update), include)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_resource_reorder
+    #[builder(on(String, into))]
+    pub async fn package_resource_reorder(
+        &self,
+        id: String,
+        order: Vec<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_resource_reorder", (json id), (json order))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_relationship_update
+    #[builder(on(String, into))]
+    pub async fn package_relationship_update(
+        &self,
+        subject: String,
+        object: String,
+        _type: String,
+        comment: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_relationship_update", (json subject), (json object), (json "type", _type), comment)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.group_update
+    #[builder(on(String, into))]
+    pub async fn group_update(
+        &self,
+        id: String,
+        name: String,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        _type: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_update", (json id), (json name), title, description, image_url, ("type", _type), state, approval_status, extras, packages, groups, users)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.organization_update
+    #[builder(on(String, into))]
+    pub async fn organization_update(
+        &self,
+        id: String,
+        name: String,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_update", (json id), (json name), title, description, image_url, state, approval_status, extras, packages, users)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.user_update
+    #[builder(on(String, into))]
+    pub async fn user_update(
+        &self,
+        id: String,
+        name: String,
+        email: String,
+        password: String,
+        fullname: Option<String>,
+        about: Option<String>,
+        image_url: Option<String>,
+        plugin_extras: Option<serde_json::Value>,
+        with_apitoken: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_update", (json id), (json name), (json email), (json password), fullname, about, image_url, plugin_extras, with_apitoken)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.task_status_update
+    #[builder(on(String, into))]
+    pub async fn task_status_update(
+        &self,
+        id: String,
+        entity_id: String,
+        entity_type: String,
+        task_type: String,
+        key: String,
+        value: Option<String>,
+        state: Option<String>,
+        last_updated: Option<String>,
+        error: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "task_status_update", (json id), (json entity_id), (json entity_type), (json task_type), (json key), value, state, last_updated, error)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.task_status_update_many
+    #[builder(on(String, into))]
+    pub async fn task_status_update_many(
+        &self,
+        data: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "task_status_update_many", (json data))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.term_translation_update
+    #[builder(on(String, into))]
+    pub async fn term_translation_update(
+        &self,
+        term: String,
+        term_translation: String,
+        lang_code: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "term_translation_update", (json term), (json term_translation), (json lang_code))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.term_translation_update_many
+    #[builder(on(String, into))]
+    pub async fn term_translation_update_many(
+        &self,
+        data: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "term_translation_update_many", (json data))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.vocabulary_update
+    #[builder(on(String, into))]
+    pub async fn vocabulary_update(
+        &self,
+        id: String,
+        name: String,
+        tags: Vec<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "vocabulary_update", (json id), (json name), (json tags))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.package_owner_org_update
+    #[builder(on(String, into))]
+    pub async fn package_owner_org_update(
+        &self,
+        id: String,
+        organization_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_owner_org_update", (json id), (json organization_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_private
+    #[builder(on(String, into))]
+    pub async fn bulk_update_private(
+        &self,
+        datasets: Vec<String>,
+        org_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "bulk_update_private", (json datasets), (json org_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_public
+    #[builder(on(String, into))]
+    pub async fn bulk_update_public(
+        &self,
+        datasets: Vec<String>,
+        org_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "bulk_update_public", (json datasets), (json org_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.bulk_update_delete
+    #[builder(on(String, into))]
+    pub async fn bulk_update_delete(
+        &self,
+        datasets: Vec<String>,
+        org_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "bulk_update_delete", (json datasets), (json org_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.config_option_update
+    #[builder(on(String, into))]
+    pub async fn config_option_update(
+        &self,
+        options: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "config_option_update", (extend options))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.package_patch
+    #[builder(on(String, into))]
+    pub async fn package_patch(
+        &self,
+        id: String,
+        name: Option<String>,
+        title: Option<String>,
+        private: Option<bool>,
+        author: Option<String>,
+        author_email: Option<String>,
+        maintainer: Option<String>,
+        maintainer_email: Option<String>,
+        license_id: Option<String>,
+        notes: Option<String>,
+        url: Option<String>,
+        version: Option<String>,
+        #[builder(into)] state: Option<State>,
+        _type: Option<String>,
+        resources: Option<Vec<serde_json::Value>>,
+        tags: Option<Vec<serde_json::Value>>,
+        extras: Option<Vec<serde_json::Value>>,
+        plugin_data: Option<serde_json::Value>,
+        relationships_as_object: Option<Vec<serde_json::Value>>,
+        relationships_as_subject: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        owner_org: Option<String>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_patch", (json id), name, title, private, author, author_email, maintainer, maintainer_email, license_id, notes, url, version, state, ("type", _type), resources, tags, extras, plugin_data, relationships_as_object, relationships_as_subject, groups, owner_org, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.resource_patch
+    #[builder(on(String, into))]
+    pub async fn resource_patch(
+        &self,
+        id: String,
+        package_id: String,
+        url: Option<String>,
+        description: Option<String>,
+        format: Option<String>,
+        hash: Option<String>,
+        name: Option<String>,
+        resource_type: Option<String>,
+        mimetype: Option<String>,
+        mimetype_inner: Option<String>,
+        cache_url: Option<String>,
+        size: Option<i32>,
+        created: Option<String>,
+        last_modified: Option<String>,
+        cache_last_updated: Option<String>,
+        upload: Option<PathBuf>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_patch", (json id), (json package_id), url, description, format, hash, name, resource_type, mimetype, mimetype_inner, cache_url, size, created, last_modified, cache_last_updated; (upload upload))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.update.group_patch
+    #[builder(on(String, into))]
+    pub async fn group_patch(
+        &self,
+        id: String,
+        name: Option<String>,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        _type: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        groups: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_patch", (json id), name, title, description, image_url, ("type", _type), state, approval_status, extras, packages, groups, users, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.organization_patch
+    #[builder(on(String, into))]
+    pub async fn organization_patch(
+        &self,
+        id: String,
+        name: Option<String>,
+        title: Option<String>,
+        description: Option<String>,
+        image_url: Option<String>,
+        #[builder(into)] state: Option<State>,
+        approval_status: Option<String>,
+        extras: Option<Vec<serde_json::Value>>,
+        packages: Option<Vec<serde_json::Value>>,
+        users: Option<Vec<serde_json::Value>>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_patch", (json id), name, title, description, image_url, state, approval_status, extras, packages, users, (extend custom_fields))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.patch.user_patch
+    #[builder(on(String, into))]
+    pub async fn user_patch(
+        &self,
+        id: String,
+        name: String,
+        email: String,
+        password: String,
+        fullname: Option<String>,
+        about: Option<String>,
+        image_url: Option<String>,
+        plugin_extras: Option<serde_json::Value>,
+        with_apitoken: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_patch", (json id), (json name), (json email), (json password), fullname, about, image_url, plugin_extras, with_apitoken)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.user_delete
+    #[builder(on(String, into))]
+    pub async fn user_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "user_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_delete
+    #[builder(on(String, into))]
+    pub async fn package_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.dataset_purge
+    #[builder(on(String, into))]
+    pub async fn dataset_purge(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "dataset_purge", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_delete
+    #[builder(on(String, into))]
+    pub async fn resource_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_view_delete
+    #[builder(on(String, into))]
+    pub async fn resource_view_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.resource_view_clear
+    #[builder(on(String, into))]
+    pub async fn resource_view_clear(
+        &self,
+        view_types: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "resource_view_clear", view_types)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_relationship_delete
+    #[builder(on(String, into))]
+    pub async fn package_relationship_delete(
+        &self,
+        subject: String,
+        object: String,
+        _type: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_relationship_delete", (json subject), (json object), (json "type", _type))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.member_delete
+    #[builder(on(String, into))]
+    pub async fn member_delete(
+        &self,
+        id: String,
+        object: String,
+        #[builder(into)] object_type: ObjectType,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "member_delete", (json id), (json object), (json object_type))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.package_collaborator_delete
+    #[builder(on(String, into))]
+    pub async fn package_collaborator_delete(
+        &self,
+        id: String,
+        user_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "package_collaborator_delete", (json id), (json user_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_delete
+    #[builder(on(String, into))]
+    pub async fn group_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_delete
+    #[builder(on(String, into))]
+    pub async fn organization_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_purge
+    #[builder(on(String, into))]
+    pub async fn group_purge(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_purge", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_purge
+    #[builder(on(String, into))]
+    pub async fn organization_purge(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_purge", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.task_status_delete
+    #[builder(on(String, into))]
+    pub async fn task_status_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "task_status_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.vocabulary_delete
+    #[builder(on(String, into))]
+    pub async fn vocabulary_delete(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "vocabulary_delete", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.tag_delete
+    #[builder(on(String, into))]
+    pub async fn tag_delete(
+        &self,
+        id: String,
+        vocabulary_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "tag_delete", (json id), (json vocabulary_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_user
+    #[builder(on(String, into))]
+    pub async fn unfollow_user(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "unfollow_user", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_dataset
+    #[builder(on(String, into))]
+    pub async fn unfollow_dataset(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "unfollow_dataset", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.group_member_delete
+    #[builder(on(String, into))]
+    pub async fn group_member_delete(
+        &self,
+        id: String,
+        username: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "group_member_delete", (json id), (json username))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.organization_member_delete
+    #[builder(on(String, into))]
+    pub async fn organization_member_delete(
+        &self,
+        id: String,
+        username: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "organization_member_delete", (json id), (json username))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.unfollow_group
+    #[builder(on(String, into))]
+    pub async fn unfollow_group(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "unfollow_group", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.job_clear
+    #[builder(on(String, into))]
+    pub async fn job_clear(
+        &self,
+        queues: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "job_clear", queues)
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.job_cancel
+    #[builder(on(String, into))]
+    pub async fn job_cancel(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "job_cancel", (json id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/api/index.html#ckan.logic.action.delete.api_token_revoke
+    #[builder(on(String, into))]
+    pub async fn api_token_revoke(
+        &self,
+        token: Option<String>,
+        jti: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "api_token_revoke", token, jti)
+    }
+
+    /// https://github.com/ckan/ckanext-scheming#scheming_dataset_schema_show
+    #[builder(on(String, into))]
+    pub async fn scheming_dataset_schema_show(
+        &self,
+        _type: String,
+        expanded: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "scheming_dataset_schema_show", (json "type", _type), expanded)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_create
+    #[builder(on(String, into))]
+    pub async fn file_create(
+        &self,
+        name: Option<String>,
+        storage: Option<String>,
+        upload: Option<PathBuf>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_create", name, storage; (upload upload))
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_register
+    #[builder(on(String, into))]
+    pub async fn file_register(
+        &self,
+        location: Option<String>,
+        storage: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_register", location, storage)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_delete
+    #[builder(on(String, into))]
+    pub async fn file_delete(&self, id: Option<String>) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_delete", id)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_show
+    #[builder(on(String, into))]
+    pub async fn file_show(&self, id: Option<String>) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_show", id)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_rename
+    #[builder(on(String, into))]
+    pub async fn file_rename(
+        &self,
+        id: Option<String>,
+        name: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_rename", id, name)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_pin
+    #[builder(on(String, into))]
+    pub async fn file_pin(&self, id: Option<String>) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_pin", id)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_unpin
+    #[builder(on(String, into))]
+    pub async fn file_unpin(&self, id: Option<String>) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "file_unpin", id)
+    }
+
+    /// https://docs.ckan.org/en/latest/api/index.html#ckan.logic.action.file.file_ownership_transfer
+    #[builder(on(String, into))]
+    pub async fn file_ownership_transfer(
+        &self,
+        id: Option<String>,
+        owner_id: Option<String>,
+        #[builder(into)] owner_type: Option<OwnerType>,
+        force: Option<bool>,
+        pin: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "file_ownership_transfer",
+            id,
+            owner_id,
+            owner_type,
+            force,
+            pin
+        )
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/create.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_create(
+        &self,
+        url: String,
+        name: String,
+        title: Option<String>,
+        notes: Option<String>,
+        source_type: String,
+        #[builder(into)] frequency: Option<HarvestFrequency>,
+        config: Option<String>,
+        owner_org: Option<String>,
+        active: Option<bool>,
+        private: Option<bool>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_create", (json url), (json name), title, notes, (json source_type), frequency, config, owner_org, active, private, (extend custom_fields))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_update(
+        &self,
+        id: String,
+        url: Option<String>,
+        name: Option<String>,
+        title: Option<String>,
+        notes: Option<String>,
+        source_type: Option<String>,
+        #[builder(into)] frequency: Option<HarvestFrequency>,
+        config: Option<String>,
+        owner_org: Option<String>,
+        active: Option<bool>,
+        private: Option<bool>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_update", (json id), url, name, title, notes, source_type, frequency, config, owner_org, active, private, (extend custom_fields))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/patch.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_patch(
+        &self,
+        id: String,
+        url: Option<String>,
+        name: Option<String>,
+        title: Option<String>,
+        notes: Option<String>,
+        source_type: Option<String>,
+        #[builder(into)] frequency: Option<HarvestFrequency>,
+        config: Option<String>,
+        owner_org: Option<String>,
+        active: Option<bool>,
+        private: Option<bool>,
+        custom_fields: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_patch", (json id), url, name, title, notes, source_type, frequency, config, owner_org, active, private, (extend custom_fields))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_show(
+        &self,
+        id: Option<String>,
+        url: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_show", id, url)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_list(
+        &self,
+        organization_id: Option<String>,
+        only_active: Option<bool>,
+        return_last_job_status: Option<bool>,
+        url: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "harvest_source_list",
+            organization_id,
+            only_active,
+            return_last_job_status,
+            url
+        )
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/delete.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_delete(
+        &self,
+        id: String,
+        clear: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_delete", (json id), clear)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+    #[builder(on(String, into))]
+    pub async fn harvest_source_clear(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_source_clear", (json id))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+    #[builder(on(String, into))]
+    pub async fn harvest_sources_job_history_clear(
+        &self,
+        keep_current: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_sources_job_history_clear", keep_current)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/create.py
+    #[builder(on(String, into))]
+    pub async fn harvest_job_create(
+        &self,
+        source_id: String,
+        run: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_job_create", (json source_id), run)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_job_list(
+        &self,
+        source_id: Option<String>,
+        #[builder(into)] status: Option<HarvestJobStatus>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_job_list", source_id, status)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_job_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_job_show", (json id))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/update.py
+    #[builder(on(String, into))]
+    pub async fn harvest_job_abort(
+        &self,
+        source_id: Option<String>,
+        id: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_job_abort", source_id, id)
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_object_show(&self, id: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_object_show", (json id))
+    }
+
+    /// https://github.com/ckan/ckanext-harvest/blob/master/ckanext/harvest/logic/action/get.py
+    #[builder(on(String, into))]
+    pub async fn harvest_log_list(
+        &self,
+        level: Option<String>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "harvest_log_list", level, limit, offset)
+    }
+
+    /// https://github.com/ckan/ckanext-xloader/blob/master/ckanext/xloader/action.py
+    #[builder(on(String, into))]
+    pub async fn xloader_submit(
+        &self,
+        resource_id: String,
+        ignore_hash: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "xloader_submit", (json resource_id), ignore_hash)
+    }
+
+    /// https://github.com/ckan/ckanext-xloader/blob/master/ckanext/xloader/action.py
+    #[builder(on(String, into))]
+    pub async fn xloader_status(
+        &self,
+        resource_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "xloader_status", (json resource_id))
+    }
+
+    /// https://github.com/ckan/ckan/blob/2.10/ckanext/datapusher/logic/action.py
+    #[builder(on(String, into))]
+    pub async fn datapusher_submit(
+        &self,
+        resource_id: String,
+        ignore_hash: Option<bool>,
+        set_url_type: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datapusher_submit", (json resource_id), ignore_hash, set_url_type)
+    }
+
+    /// https://github.com/ckan/ckan/blob/2.10/ckanext/datapusher/logic/action.py
+    #[builder(on(String, into))]
+    pub async fn datapusher_status(
+        &self,
+        resource_id: String,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datapusher_status", (json resource_id))
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_create
+    #[builder(on(String, into))]
+    pub async fn datastore_create(
+        &self,
+        resource_id: Option<String>,
+        force: Option<bool>,
+        resource: Option<serde_json::Value>,
+        #[builder(into)] aliases: Option<serde_json::Value>,
+        fields: Option<Vec<serde_json::Value>>,
+        records: Option<Vec<serde_json::Value>>,
+        #[builder(into)] primary_key: Option<serde_json::Value>,
+        #[builder(into)] indexes: Option<serde_json::Value>,
+        triggers: Option<Vec<serde_json::Value>>,
+        calculate_record_count: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(
+            &self,
+            "datastore_create",
+            resource_id,
+            force,
+            resource,
+            aliases,
+            fields,
+            records,
+            primary_key,
+            indexes,
+            triggers,
+            calculate_record_count
+        )
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_upsert
+    #[builder(on(String, into))]
+    pub async fn datastore_upsert(
+        &self,
+        resource_id: String,
+        force: Option<bool>,
+        records: Option<Vec<serde_json::Value>>,
+        #[builder(into)] method: Option<UpsertMethod>,
+        dry_run: Option<bool>,
+        calculate_record_count: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datastore_upsert", (json resource_id), force, records, method, dry_run, calculate_record_count)
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_delete
+    #[builder(on(String, into))]
+    pub async fn datastore_delete(
+        &self,
+        resource_id: String,
+        force: Option<bool>,
+        filters: Option<serde_json::Value>,
+        calculate_record_count: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datastore_delete", (json resource_id), force, filters, calculate_record_count)
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_info
+    #[builder(on(String, into))]
+    pub async fn datastore_info(
+        &self,
+        id: String,
+        include_meta: Option<bool>,
+        include_fields_schema: Option<bool>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datastore_info", (json id), include_meta, include_fields_schema)
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_search
+    #[builder(on(String, into))]
+    pub async fn datastore_search(
+        &self,
+        resource_id: String,
+        filters: Option<serde_json::Value>,
+        #[builder(into)] q: Option<serde_json::Value>,
+        full_text: Option<String>,
+        distinct: Option<bool>,
+        plain: Option<bool>,
+        language: Option<String>,
+        limit: Option<u32>,
+        offset: Option<u32>,
+        #[builder(into)] fields: Option<serde_json::Value>,
+        sort: Option<String>,
+        include_total: Option<bool>,
+        total_estimation_threshold: Option<i32>,
+        records_format: Option<String>,
+    ) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datastore_search", (json resource_id), filters, q, full_text, distinct, plain, language, limit, offset, fields, sort, include_total, total_estimation_threshold, records_format)
+    }
+
+    /// https://docs.ckan.org/en/2.11/maintaining/datastore.html#ckanext.datastore.logic.action.datastore_search_sql
+    #[builder(on(String, into))]
+    pub async fn datastore_search_sql(&self, sql: String) -> Result<serde_json::Value, CKANError> {
+        post!(&self, "datastore_search_sql", (json sql))
+    }
+}
@@ -0,0 +1,204 @@
+//! Idempotent create-or-update helpers for re-runnable provisioning scripts.
+//!
+//! Each `ensure_*` action looks the entity up by name with the matching `*_show` action, creates
+//! it when it does not exist, and otherwise patches only the fields that differ from `fields`.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::ensure::Ensured;
+//!
+//! let ensured = ckan
+//!     .ensure_organization()
+//!     .name("city-of-example")
+//!     .fields(serde_json::json!({ "title": "City of Example" }))
+//!     .call()
+//!     .await?;
+//! if let Ensured::Updated { changed, .. } = ensured {
+//!     println!("updated {changed:?}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError};
+use bon::bon;
+use serde_json::json;
+
+/// Fields that CKAN never returns from `*_show`, so they are only sent on creation.
+const WRITE_ONLY_FIELDS: [&str; 1] = ["password"];
+
+/// What an `ensure_*` action did. Each variant holds the entity as returned by CKAN.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Ensured {
+    Created(serde_json::Value),
+    Updated {
+        entity: serde_json::Value,
+        /// Names of the patched fields.
+        changed: Vec<String>,
+    },
+    Unchanged(serde_json::Value),
+}
+
+impl Ensured {
+    pub fn entity(&self) -> &serde_json::Value {
+        match self {
+            Self::Created(entity) | Self::Updated { entity, .. } | Self::Unchanged(entity) => {
+                entity
+            }
+        }
+    }
+}
+
+/// Whether `desired` is already reflected in `current`.
+///
+/// Objects only need to match on the keys present in `desired` and arrays are compared without
+/// regard to order, so e.g. `[{"name": "air"}]` matches the full tag objects returned by CKAN.
+pub fn is_subset(desired: &serde_json::Value, current: &serde_json::Value) -> bool {
+    match (desired, current) {
+        (serde_json::Value::Object(desired), serde_json::Value::Object(current)) => desired
+            .iter()
+            .all(|(key, value)| is_subset(value, current.get(key).unwrap_or(&json!(null)))),
+        (serde_json::Value::Array(desired), serde_json::Value::Array(current)) => {
+            desired.len() == current.len() && matches_all(desired, current)
+        }
+        // CKAN returns missing text fields as either null or "".
+        (serde_json::Value::String(desired), serde_json::Value::Null) => desired.is_empty(),
+        (desired, current) => desired == current,
+    }
+}
+
+/// Whether every item of `desired` is a subset of a different item of `current`, so that e.g.
+/// `[a, a]` does not match `[a, b]`.
+fn matches_all(desired: &[serde_json::Value], current: &[serde_json::Value]) -> bool {
+    // Find a matching with augmenting paths: `owner[j]` is the desired item using `current[j]`.
+    fn assign(
+        index: usize,
+        desired: &[serde_json::Value],
+        current: &[serde_json::Value],
+        owner: &mut [Option<usize>],
+        visited: &mut [bool],
+    ) -> bool {
+        for (j, item) in current.iter().enumerate() {
+            if visited[j] || !is_subset(&desired[index], item) {
+                continue;
+            }
+            visited[j] = true;
+            if owner[j].is_none_or(|other| assign(other, desired, current, owner, visited)) {
+                owner[j] = Some(index);
+                return true;
+            }
+        }
+        false
+    }
+    let mut owner = vec![None; current.len()];
+    (0..desired.len()).all(|index| {
+        let mut visited = vec![false; current.len()];
+        assign(index, desired, current, &mut owner, &mut visited)
+    })
+}
+
+fn is_not_found(error: &CKANError) -> bool {
+    matches!(error, CKANError::ActionError(error) if error["__type"] == "Not Found Error")
+}
+
+impl CKAN {
+    async fn ensure(
+        &self,
+        entity_type: &str,
+        name: String,
+        fields: Option<serde_json::Value>,
+        show_options: serde_json::Value,
+    ) -> Result<Ensured, CKANError> {
+        let mut desired = match fields {
+            Some(serde_json::Value::Object(fields)) => fields,
+            _ => serde_json::Map::new(),
+        };
+        desired.insert("name".to_string(), json!(name));
+
+        let mut show_body = show_options;
+        show_body["id"] = json!(name);
+        let current = match self
+            .call_action(&format!("{entity_type}_show"), show_body)
+            .await
+        {
+            Ok(current) => current,
+            Err(error) if is_not_found(&error) => {
+                let created = self
+                    .call_action(&format!("{entity_type}_create"), desired.into())
+                    .await?;
+                return Ok(Ensured::Created(created));
+            }
+            Err(error) => return Err(error),
+        };
+
+        let mut patch = serde_json::Map::new();
+        for (field, value) in desired {
+            if !WRITE_ONLY_FIELDS.contains(&field.as_str())
+                && !is_subset(&value, current.get(&field).unwrap_or(&json!(null)))
+            {
+                patch.insert(field, value);
+            }
+        }
+        if patch.is_empty() {
+            return Ok(Ensured::Unchanged(current));
+        }
+        let changed = patch.keys().cloned().collect();
+        patch.insert("id".to_string(), current["id"].clone());
+        let entity = self
+            .call_action(&format!("{entity_type}_patch"), patch.into())
+            .await?;
+        Ok(Ensured::Updated { entity, changed })
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// Create the package `name` with `fields`, or patch the fields that differ if it exists.
+    #[builder(on(String, into))]
+    pub async fn ensure_package(
+        &self,
+        name: String,
+        fields: Option<serde_json::Value>,
+    ) -> Result<Ensured, CKANError> {
+        self.ensure("package", name, fields, json!({})).await
+    }
+
+    /// Create the organization `name` with `fields`, or patch the fields that differ if it exists.
+    #[builder(on(String, into))]
+    pub async fn ensure_organization(
+        &self,
+        name: String,
+        fields: Option<serde_json::Value>,
+    ) -> Result<Ensured, CKANError> {
+        self.ensure(
+            "organization",
+            name,
+            fields,
+            json!({ "include_datasets": false }),
+        )
+        .await
+    }
+
+    /// Create the group `name` with `fields`, or patch the fields that differ if it exists.
+    #[builder(on(String, into))]
+    pub async fn ensure_group(
+        &self,
+        name: String,
+        fields: Option<serde_json::Value>,
+    ) -> Result<Ensured, CKANError> {
+        self.ensure("group", name, fields, json!({ "include_datasets": false }))
+            .await
+    }
+
+    /// Create the user `name` with `fields`, or patch the fields that differ if it exists.
+    ///
+    /// `password` is only used when the user is created, since CKAN never returns it.
+    #[builder(on(String, into))]
+    pub async fn ensure_user(
+        &self,
+        name: String,
+        fields: Option<serde_json::Value>,
+    ) -> Result<Ensured, CKANError> {
+        self.ensure("user", name, fields, json!({})).await
+    }
+}
@@ -0,0 +1,111 @@
+//! Run ckanext-harvest jobs and follow them to completion.
+//!
+//! The harvest actions themselves (`harvest_source_create`, `harvest_job_list`, ...) are regular
+//! builder methods on `CKAN`. [`CKAN::harvest_run`] creates a job for a source and polls it until
+//! it is finished:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use std::time::Duration;
+//!
+//! let job = ckan
+//!     .harvest_run()
+//!     .source_id("city-of-example-csw")
+//!     .poll_interval(Duration::from_secs(30))
+//!     .on_progress(&|job| println!("{}: {} added", job.status, job.stats.added))
+//!     .call()
+//!     .await?;
+//! println!("{} errors", job.stats.errored);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Jobs only progress while the harvester `gather` and `fetch` consumers are running on the
+//! server.
+
+use crate::{CKAN, CKANError, action_result, params::HarvestJobStatus, with_timeout};
+use bon::bon;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Number of harvest objects of a job by outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HarvestStats {
+    pub added: u64,
+    pub updated: u64,
+    pub deleted: u64,
+    pub errored: u64,
+    #[serde(rename = "not modified")]
+    pub not_modified: u64,
+}
+
+/// A harvest job as returned by `harvest_job_show` or `harvest_job_create`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HarvestJob {
+    pub id: String,
+    pub source_id: String,
+    pub status: HarvestJobStatus,
+    pub created: Option<String>,
+    pub gather_started: Option<String>,
+    pub gather_finished: Option<String>,
+    pub finished: Option<String>,
+    #[serde(default)]
+    pub stats: HarvestStats,
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl HarvestJob {
+    /// Whether the job will not change anymore.
+    pub fn is_done(&self) -> bool {
+        matches!(
+            self.status,
+            HarvestJobStatus::Finished | HarvestJobStatus::Aborted
+        )
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// Create a job for the harvest source `source_id` and wait until it is finished or aborted,
+    /// checking every `poll_interval` (10 seconds by default). `on_progress` is called with the
+    /// job every time it is checked.
+    ///
+    /// Fails with `CKANError::Timeout` if the job is not done after `timeout` (no limit by
+    /// default), and with `CKANError::Unsupported` if ckanext-harvest is not enabled.
+    #[builder(on(String, into))]
+    pub async fn harvest_run(
+        &self,
+        source_id: String,
+        poll_interval: Option<Duration>,
+        timeout: Option<Duration>,
+        on_progress: Option<&(dyn Fn(&HarvestJob) + Sync)>,
+    ) -> Result<HarvestJob, CKANError> {
+        self.require_extension("harvest", "harvest_job_create")
+            .await?;
+        let job = action_result(
+            self.harvest_job_create()
+                .source_id(&source_id)
+                .run(true)
+                .call()
+                .await?,
+        )?;
+        let mut job: HarvestJob = serde_json::from_value(job)?;
+        let what = format!("harvest job {} of source {source_id}", job.id);
+        with_timeout(timeout, || what, async {
+            loop {
+                if let Some(on_progress) = on_progress {
+                    on_progress(&job);
+                }
+                if job.is_done() {
+                    return Ok(job);
+                }
+                tokio::time::sleep(poll_interval.unwrap_or(Duration::from_secs(10))).await;
+                let shown = action_result(self.harvest_job_show().id(&job.id).call().await?)?;
+                job = serde_json::from_value(shown)?;
+            }
+        })
+        .await
+    }
+}
@@ -0,0 +1,128 @@
+//! Follow background jobs and their queues.
+//!
+//! CKAN lists a job with `job_list` until a worker picks it up. [`CKAN::await_job`] waits for
+//! that, and [`CKAN::queue_stats`] reports the size of every queue at an interval:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use futures::StreamExt;
+//! use std::time::Duration;
+//!
+//! let job = ckan.await_job().id("4b1d2c3e-5f6a-7b8c-9d0e-1f2a3b4c5d6e").call().await?;
+//! println!("{} left the {} queue", job.id, job.queue);
+//!
+//! let mut stats = ckan.queue_stats().interval(Duration::from_secs(60)).call();
+//! while let Some(queues) = stats.next().await {
+//!     for queue in queues? {
+//!         println!("{}: {} jobs", queue.queue, queue.jobs);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError, action_result, with_timeout};
+use bon::bon;
+use futures::{StreamExt, stream};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+
+/// A background job as returned by `job_show` and `job_list`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Job {
+    pub id: String,
+    pub title: Option<String>,
+    pub created: Option<String>,
+    /// Name of the queue, e.g. `default`.
+    pub queue: String,
+}
+
+/// The jobs waiting in a queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueueStats {
+    pub queue: String,
+    pub jobs: usize,
+    /// When the job that has waited longest was created.
+    pub oldest: Option<String>,
+}
+
+#[bon]
+impl CKAN {
+    /// Wait until the job `id` is no longer queued, i.e. a worker picked it up or it was
+    /// cancelled, and return it as `job_show` returned it before waiting. `job_show` keeps
+    /// finding finished and failed jobs, so the queue of the job is polled with `job_list`.
+    ///
+    /// The queue is checked after `initial_interval` (1 second by default), then at twice the
+    /// previous interval up to `max_interval` (30 seconds by default). Fails with
+    /// `CKANError::Timeout` if the job is still queued after `timeout` (no limit by default).
+    #[builder(on(String, into))]
+    pub async fn await_job(
+        &self,
+        id: String,
+        initial_interval: Option<Duration>,
+        max_interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Job, CKANError> {
+        let job: Job =
+            serde_json::from_value(action_result(self.job_show().id(&id).call().await?)?)?;
+        let max_interval = max_interval.unwrap_or(Duration::from_secs(30));
+        let mut interval = initial_interval
+            .unwrap_or(Duration::from_secs(1))
+            .min(max_interval);
+        let what = || format!("waiting for job {id} to leave the {} queue", job.queue);
+        with_timeout(timeout, what, async {
+            loop {
+                tokio::time::sleep(interval).await;
+                let queued = action_result(
+                    self.job_list()
+                        .queues(vec![job.queue.clone()])
+                        .call()
+                        .await?,
+                )?;
+                let queued: Vec<Job> = serde_json::from_value(queued)?;
+                if !queued.iter().any(|queued| queued.id == job.id) {
+                    return Ok(());
+                }
+                interval = (interval * 2).min(max_interval);
+            }
+        })
+        .await?;
+        Ok(job)
+    }
+
+    /// Every `interval` (10 seconds by default), the number of jobs in each queue with jobs, as
+    /// listed by `job_list`. The first statistics are fetched right away.
+    #[builder]
+    pub fn queue_stats<'a>(
+        &'a self,
+        interval: Option<Duration>,
+    ) -> futures::stream::BoxStream<'a, Result<Vec<QueueStats>, CKANError>> {
+        let interval = interval.unwrap_or(Duration::from_secs(10));
+        stream::unfold(true, move |first| async move {
+            if !first {
+                tokio::time::sleep(interval).await;
+            }
+            let stats = async {
+                let jobs = action_result(self.job_list().call().await?)?;
+                let jobs: Vec<Job> = serde_json::from_value(jobs)?;
+                let mut queues: BTreeMap<String, QueueStats> = BTreeMap::new();
+                for job in jobs {
+                    let stats = queues.entry(job.queue.clone()).or_insert(QueueStats {
+                        queue: job.queue,
+                        jobs: 0,
+                        oldest: None,
+                    });
+                    stats.jobs += 1;
+                    if let Some(created) = job.created
+                        && stats.oldest.as_ref().is_none_or(|oldest| created < *oldest)
+                    {
+                        stats.oldest = Some(created);
+                    }
+                }
+                Ok(queues.into_values().collect())
+            };
+            Some((stats.await, false))
+        })
+        .boxed()
+    }
+}
@@ -0,0 +1,253 @@
+//! Load resources into the DataStore with XLoader or DataPusher and wait for the result.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! let resource = ckan
+//!     .resource_create_and_load()
+//!     .package_id("air-quality")
+//!     .upload("measurements.csv".into())
+//!     .format("CSV")
+//!     .call()
+//!     .await?;
+//! // The rows of `measurements.csv` are now in the DataStore.
+//! println!("{}", resource.id);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A failed job gives a `CKANError::LoadError` with the messages the loader logged.
+
+use crate::{CKAN, CKANError, action_result, types::Resource, with_timeout};
+use bon::bon;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{path::PathBuf, time::Duration};
+
+/// The extension that loads uploaded files into the DataStore.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Loader {
+    XLoader,
+    DataPusher,
+}
+
+impl Loader {
+    fn submit_action(self) -> &'static str {
+        match self {
+            Self::XLoader => "xloader_submit",
+            Self::DataPusher => "datapusher_submit",
+        }
+    }
+
+    fn status_action(self) -> &'static str {
+        match self {
+            Self::XLoader => "xloader_status",
+            Self::DataPusher => "datapusher_status",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoaderState {
+    Pending,
+    Submitting,
+    Running,
+    Complete,
+    Error,
+    #[serde(other)]
+    Other,
+}
+
+/// The last job of a resource, as returned by `xloader_status` or `datapusher_status`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoaderStatus {
+    pub status: LoaderState,
+    pub job_id: Option<String>,
+    pub last_updated: Option<String>,
+    pub error: Option<serde_json::Value>,
+    /// Details of the job, including its `logs`.
+    pub task_info: Option<serde_json::Value>,
+}
+
+impl LoaderStatus {
+    /// The messages logged by the job.
+    pub fn logs(&self) -> Vec<String> {
+        self.task_info
+            .iter()
+            .flat_map(|info| info["logs"].as_array().into_iter().flatten())
+            .filter_map(|log| Some(log["message"].as_str()?.to_string()))
+            .collect()
+    }
+
+    fn error_message(&self) -> String {
+        let error = self.error.as_ref().unwrap_or(&serde_json::Value::Null);
+        match error.as_str().or(error["message"].as_str()) {
+            Some(message) => message.to_string(),
+            None if error.is_null() => "the job failed without an error message".to_string(),
+            None => error.to_string(),
+        }
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// The DataStore loader enabled on the server, preferring XLoader if both are.
+    ///
+    /// Fails with `CKANError::Unsupported` if neither is enabled.
+    pub async fn loader(&self) -> Result<Loader, CKANError> {
+        let capabilities = self.capabilities().await?;
+        if capabilities.has_extension("xloader") {
+            return Ok(Loader::XLoader);
+        }
+        if capabilities.has_extension("datapusher") {
+            return Ok(Loader::DataPusher);
+        }
+        if self.has_action("xloader_submit").await? {
+            return Ok(Loader::XLoader);
+        }
+        if self.has_action("datapusher_submit").await? {
+            return Ok(Loader::DataPusher);
+        }
+        Err(CKANError::Unsupported(
+            "neither ckanext-xloader nor datapusher is enabled on the server".to_string(),
+        ))
+    }
+
+    /// Submit the resource `resource_id` to the DataStore loader and wait until the job is
+    /// complete, checking every `poll_interval` (5 seconds by default). The status of the job the
+    /// resource had before the submission is ignored.
+    ///
+    /// Fails with `CKANError::LoadError` if the job fails, and with `CKANError::Timeout` if it is
+    /// not complete after `timeout` (no limit by default).
+    #[builder(on(String, into))]
+    pub async fn datastore_load(
+        &self,
+        resource_id: String,
+        ignore_hash: Option<bool>,
+        poll_interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<LoaderStatus, CKANError> {
+        let loader = self.loader().await?;
+        let previous = self.loader_status(loader, &resource_id).await?;
+        let body = json!({
+            "resource_id": resource_id,
+            "ignore_hash": ignore_hash.unwrap_or(false),
+        });
+        // The loader answers `false` when it did not queue a new job, e.g. because one is already
+        // pending, which is then the job to wait for.
+        let queued = self.call_action(loader.submit_action(), body).await? != false;
+        self.await_load()
+            .loader(loader)
+            .resource_id(resource_id)
+            .maybe_previous_job_id(
+                previous
+                    .and_then(|previous| previous.job_id)
+                    .filter(|_| queued),
+            )
+            .queued(queued)
+            .maybe_poll_interval(poll_interval)
+            .maybe_timeout(timeout)
+            .call()
+            .await
+    }
+
+    /// The status of the last job for `resource_id`, or `None` if there is none yet.
+    async fn loader_status(
+        &self,
+        loader: Loader,
+        resource_id: &str,
+    ) -> Result<Option<LoaderStatus>, CKANError> {
+        match self
+            .call_action(
+                loader.status_action(),
+                json!({ "resource_id": resource_id }),
+            )
+            .await
+        {
+            Ok(status) => Ok(Some(serde_json::from_value(status)?)),
+            Err(CKANError::ActionError(error)) if error["__type"] == "Not Found Error" => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Wait until the loader completed a job for `resource_id` other than `previous_job_id`.
+    /// Job ids are compared rather than times, as the clocks of client and server can differ.
+    /// Unless `queued`, a missing job means the loader did not queue one.
+    #[builder]
+    async fn await_load(
+        &self,
+        loader: Loader,
+        resource_id: String,
+        previous_job_id: Option<String>,
+        queued: bool,
+        poll_interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<LoaderStatus, CKANError> {
+        let what = format!("loading resource {resource_id} into the DataStore");
+        with_timeout(timeout, || what, async {
+            loop {
+                // There is no job until the loader picked up the submission.
+                match self.loader_status(loader, &resource_id).await? {
+                    Some(status)
+                        if previous_job_id.is_some() && status.job_id == previous_job_id => {}
+                    Some(status) if status.status == LoaderState::Complete => return Ok(status),
+                    Some(status) if status.status == LoaderState::Error => {
+                        return Err(CKANError::LoadError {
+                            message: status.error_message(),
+                            logs: status.logs(),
+                            resource_id,
+                        });
+                    }
+                    None if !queued => {
+                        return Err(CKANError::Unsupported(format!(
+                            "{} did not queue resource {resource_id}, e.g. because of its format",
+                            loader.submit_action()
+                        )));
+                    }
+                    _ => {}
+                }
+                tokio::time::sleep(poll_interval.unwrap_or(Duration::from_secs(5))).await;
+            }
+        })
+        .await
+    }
+
+    /// Upload a file as a new resource of `package_id` and wait until the loader has loaded it
+    /// into the DataStore. XLoader and DataPusher queue new uploads by themselves, so the job
+    /// they queued is awaited as in [`CKAN::datastore_load`] instead of submitting another one.
+    /// If the loader skips the file, e.g. because of its format, there is no job to wait for, so
+    /// this fails with `CKANError::Timeout` after `timeout` (1 hour by default).
+    #[builder(on(String, into))]
+    pub async fn resource_create_and_load(
+        &self,
+        package_id: String,
+        upload: PathBuf,
+        name: Option<String>,
+        format: Option<String>,
+        description: Option<String>,
+        poll_interval: Option<Duration>,
+        timeout: Option<Duration>,
+    ) -> Result<Resource, CKANError> {
+        // Fail before uploading if there is nothing to load the file.
+        let loader = self.loader().await?;
+        let resource = self
+            .resource_create()
+            .package_id(package_id)
+            .upload(upload)
+            .maybe_name(name)
+            .maybe_format(format)
+            .maybe_description(description)
+            .call()
+            .await?;
+        let resource: Resource = serde_json::from_value(action_result(resource)?)?;
+        self.await_load()
+            .loader(loader)
+            .resource_id(resource.id.to_string())
+            .queued(true)
+            .maybe_poll_interval(poll_interval)
+            .timeout(timeout.unwrap_or(Duration::from_secs(60 * 60)))
+            .call()
+            .await?;
+        Ok(resource)
+    }
+}
@@ -0,0 +1,433 @@
+//! Round-trip package metadata through CSV so it can be edited in bulk in a spreadsheet.
+//!
+//! Every package becomes one row with one column per field. `tags` and `groups` are written as
+//! `;`-separated names, each extra gets its own `extras.<key>` column and any other non-scalar
+//! value is written as JSON. On import each row is compared against the live package and only
+//! the changed fields are sent with `package_patch`.
+
+use crate::{CKAN, CKANError, action_result};
+use bon::bon;
+use futures::{StreamExt, TryStreamExt, stream};
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Fields that are too large or nested to edit as a single cell and are left out of exports.
+const EXCLUDED_FIELDS: [&str; 4] = [
+    "organization",
+    "relationships_as_object",
+    "relationships_as_subject",
+    "resources",
+];
+
+/// Fields that are exported for reference but computed by CKAN, so edits to them are ignored.
+const READ_ONLY_FIELDS: [&str; 10] = [
+    "id",
+    "creator_user_id",
+    "isopen",
+    "license_title",
+    "license_url",
+    "metadata_created",
+    "metadata_modified",
+    "num_resources",
+    "num_tags",
+    "revision_id",
+];
+
+const LIST_SEPARATOR: &str = ";";
+const EXTRAS_PREFIX: &str = "extras.";
+
+/// A single field that differs between a CSV row and the live package.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// The changes found for a CSV row and the `package_patch` body that applies them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowDiff {
+    pub changes: Vec<FieldChange>,
+    pub patch: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowStatus {
+    /// The row matches the live package.
+    Unchanged,
+    /// The row has changes that were not applied because of a dry run.
+    Planned,
+    /// The changes were applied with `package_patch`.
+    Applied,
+    /// The row could not be turned into a patch. Holds one message per invalid cell.
+    Invalid(Vec<String>),
+    /// CKAN rejected the lookup or the patch. Holds the `error` object of the response.
+    Failed(serde_json::Value),
+}
+
+/// Outcome of importing a single CSV row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowReport {
+    /// 1-based index of the data row, not counting the header.
+    pub row: usize,
+    /// The `id` (or `name` when no `id` was given) used to look up the package.
+    pub id: Option<String>,
+    pub changes: Vec<FieldChange>,
+    pub status: RowStatus,
+}
+
+fn cell(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.to_owned(),
+        _ => value.to_string(),
+    }
+}
+
+fn join_names(values: &serde_json::Value) -> String {
+    values
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.get("name").and_then(|name| name.as_str()))
+                .collect::<Vec<_>>()
+                .join(LIST_SEPARATOR)
+        })
+        .unwrap_or_default()
+}
+
+/// Split a cell of names back into a list, keeping the whole dict of the items that are already
+/// in `current` (e.g. the `vocabulary_id` of a tag).
+fn split_names(cell: &str, current: Option<&serde_json::Value>) -> serde_json::Value {
+    let current = current.and_then(serde_json::Value::as_array);
+    cell.split(LIST_SEPARATOR)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            current
+                .into_iter()
+                .flatten()
+                .find(|item| item["name"] == name)
+                .cloned()
+                .unwrap_or_else(|| json!({ "name": name }))
+        })
+        .collect()
+}
+
+/// Flatten a package (as returned by `package_show` or `package_search`) into CSV cells.
+pub fn flatten_package(package: &serde_json::Value) -> BTreeMap<String, String> {
+    let mut row = BTreeMap::new();
+    let Some(fields) = package.as_object() else {
+        return row;
+    };
+    for (field, value) in fields {
+        match field.as_str() {
+            field if EXCLUDED_FIELDS.contains(&field) => {}
+            "tags" | "groups" => {
+                row.insert(field.to_owned(), join_names(value));
+            }
+            "extras" => {
+                for extra in value.as_array().into_iter().flatten() {
+                    if let Some(key) = extra.get("key").and_then(|key| key.as_str()) {
+                        row.insert(
+                            format!("{EXTRAS_PREFIX}{key}"),
+                            cell(extra.get("value").unwrap_or(&serde_json::Value::Null)),
+                        );
+                    }
+                }
+            }
+            _ => {
+                row.insert(field.to_owned(), cell(value));
+            }
+        }
+    }
+    row
+}
+
+/// Turn an edited cell back into JSON, using the type of the current value as a guide.
+fn parse_cell(
+    field: &str,
+    new: &str,
+    current: Option<&serde_json::Value>,
+) -> Result<serde_json::Value, String> {
+    match (field, current) {
+        ("tags" | "groups", current) => Ok(split_names(new, current)),
+        (_, Some(serde_json::Value::Bool(_))) => match new.trim().to_lowercase().as_str() {
+            "true" => Ok(json!(true)),
+            "false" => Ok(json!(false)),
+            _ => Err(format!("{field}: expected true or false, found {new:?}")),
+        },
+        (_, Some(serde_json::Value::Number(_))) => serde_json::from_str::<serde_json::Number>(new)
+            .map(serde_json::Value::Number)
+            .map_err(|_| format!("{field}: expected a number, found {new:?}")),
+        (_, Some(serde_json::Value::Array(_) | serde_json::Value::Object(_))) => {
+            serde_json::from_str(new).map_err(|e| format!("{field}: invalid JSON ({e})"))
+        }
+        _ => Ok(json!(new)),
+    }
+}
+
+/// Compare a CSV row against the live package and build the `package_patch` body for it.
+///
+/// Columns missing from the row and read-only columns are left untouched. Returns one message
+/// per invalid cell when the row cannot be converted.
+pub fn diff_package(
+    current: &serde_json::Value,
+    row: &BTreeMap<String, String>,
+) -> Result<RowDiff, Vec<String>> {
+    let flat = flatten_package(current);
+    let mut changes = Vec::new();
+    let mut errors = Vec::new();
+    let mut patch = serde_json::Map::new();
+    let mut extras: Vec<(String, serde_json::Value)> = current["extras"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|extra| {
+            Some((
+                extra.get("key")?.as_str()?.to_owned(),
+                extra.get("value")?.to_owned(),
+            ))
+        })
+        .collect();
+    let mut extras_changed = false;
+
+    for (field, new) in row {
+        if READ_ONLY_FIELDS.contains(&field.as_str()) || EXCLUDED_FIELDS.contains(&field.as_str()) {
+            continue;
+        }
+        let old = flat.get(field).cloned().unwrap_or_default();
+        if old == *new {
+            continue;
+        }
+        if let Some(key) = field.strip_prefix(EXTRAS_PREFIX) {
+            extras.retain(|(existing, _)| existing != key);
+            if !new.is_empty() {
+                extras.push((key.to_owned(), json!(new)));
+            }
+            extras_changed = true;
+        } else {
+            match parse_cell(field, new, current.get(field)) {
+                Ok(value) => {
+                    patch.insert(field.to_owned(), value);
+                }
+                Err(error) => {
+                    errors.push(error);
+                    continue;
+                }
+            }
+        }
+        changes.push(FieldChange {
+            field: field.to_owned(),
+            old,
+            new: new.to_owned(),
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    if extras_changed {
+        patch.insert(
+            "extras".to_string(),
+            extras
+                .into_iter()
+                .map(|(key, value)| json!({ "key": key, "value": value }))
+                .collect(),
+        );
+    }
+    if !patch.is_empty() {
+        patch.insert("id".to_string(), current["id"].clone());
+    }
+    Ok(RowDiff {
+        changes,
+        patch: serde_json::Value::Object(patch),
+    })
+}
+
+fn failure(error: CKANError) -> RowStatus {
+    match error {
+        CKANError::ActionError(error) => RowStatus::Failed(error),
+        error => RowStatus::Failed(json!(error.to_string())),
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// The packages matched by a search, flattened into CSV cells, one page of `rows` at a time.
+    fn metadata_pages<'a>(
+        &'a self,
+        q: Option<&'a str>,
+        fq: Option<&'a str>,
+        include_private: Option<bool>,
+        include_drafts: Option<bool>,
+        rows: i32,
+    ) -> impl futures::Stream<Item = Result<Vec<BTreeMap<String, String>>, CKANError>> + 'a {
+        stream::try_unfold(Some(0), move |start| async move {
+            let Some(start) = start else {
+                return Ok(None);
+            };
+            let result = action_result(
+                self.package_search()
+                    .q(q.unwrap_or("*:*"))
+                    .maybe_fq(fq)
+                    .maybe_include_private(include_private)
+                    .maybe_include_drafts(include_drafts)
+                    .rows(rows)
+                    .start(start)
+                    .call()
+                    .await?,
+            )?;
+            let page = result["results"].as_array().cloned().unwrap_or_default();
+            let count = result["count"].as_i64().unwrap_or_default();
+            let next = start + page.len() as i32;
+            let next = (!page.is_empty() && (next as i64) < count).then_some(next);
+            Ok(Some((page.iter().map(flatten_package).collect(), next)))
+        })
+    }
+
+    /// Export every package matched by `q`/`fq` as CSV, one row per package.
+    ///
+    /// Pages through `package_search` `rows` packages at a time (1000 by default), writing each
+    /// page before fetching the next, and returns the number of rows written. Without `columns`,
+    /// a first pass over the search collects the columns of all packages.
+    #[builder(on(String, into))]
+    pub async fn metadata_export<W: std::io::Write>(
+        &self,
+        writer: W,
+        q: Option<String>,
+        fq: Option<String>,
+        include_private: Option<bool>,
+        include_drafts: Option<bool>,
+        rows: Option<i32>,
+        /// Columns to export after `id` and `name`.
+        columns: Option<Vec<String>>,
+    ) -> Result<usize, CKANError> {
+        let rows = rows.unwrap_or(1000);
+        let pages = || {
+            self.metadata_pages(
+                q.as_deref(),
+                fq.as_deref(),
+                include_private,
+                include_drafts,
+                rows,
+            )
+        };
+        let columns: BTreeSet<String> = match columns {
+            Some(columns) => columns.into_iter().collect(),
+            None => {
+                pages()
+                    .try_fold(BTreeSet::new(), |mut columns, page| async move {
+                        columns.extend(page.into_iter().flat_map(BTreeMap::into_keys));
+                        Ok(columns)
+                    })
+                    .await?
+            }
+        };
+        let mut header = vec!["id", "name"];
+        header.extend(
+            columns
+                .iter()
+                .map(String::as_str)
+                .filter(|column| !["id", "name"].contains(column)),
+        );
+
+        let mut csv_writer = csv::Writer::from_writer(writer);
+        csv_writer.write_record(&header)?;
+        let mut written = 0;
+        let mut pages = std::pin::pin!(pages());
+        while let Some(page) = pages.next().await {
+            for package in page? {
+                csv_writer.write_record(
+                    header
+                        .iter()
+                        .map(|column| package.get(*column).map(String::as_str).unwrap_or_default()),
+                )?;
+                written += 1;
+            }
+            csv_writer.flush()?;
+        }
+        Ok(written)
+    }
+
+    /// Apply an edited CSV produced by `metadata_export` with `package_patch`.
+    ///
+    /// Rows are identified by their `id` column (or `name` when `id` is empty). With
+    /// `dry_run(true)` the changes are computed and reported but nothing is sent. A failing row
+    /// does not stop the import; its error is reported in the returned `RowReport`.
+    #[builder]
+    pub async fn metadata_import<R: std::io::Read>(
+        &self,
+        reader: R,
+        dry_run: Option<bool>,
+    ) -> Result<Vec<RowReport>, CKANError> {
+        let dry_run = dry_run.unwrap_or(false);
+        let mut csv_reader = csv::Reader::from_reader(reader);
+        let header = csv_reader.headers()?.clone();
+        let mut reports = Vec::new();
+        for (index, record) in csv_reader.records().enumerate() {
+            let record = record?;
+            let row: BTreeMap<String, String> = header
+                .iter()
+                .zip(record.iter())
+                .map(|(column, value)| (column.to_owned(), value.to_owned()))
+                .collect();
+            let id = ["id", "name"]
+                .iter()
+                .filter_map(|column| row.get(*column))
+                .find(|value| !value.is_empty())
+                .cloned();
+            let mut report = RowReport {
+                row: index + 1,
+                id: id.clone(),
+                changes: Vec::new(),
+                status: RowStatus::Unchanged,
+            };
+            let Some(id) = id else {
+                report.status = RowStatus::Invalid(vec!["row has no id or name".to_string()]);
+                reports.push(report);
+                continue;
+            };
+            let current = match self
+                .package_show()
+                .id(&id)
+                .call()
+                .await
+                .and_then(action_result)
+            {
+                Ok(current) => current,
+                Err(error) => {
+                    report.status = failure(error);
+                    reports.push(report);
+                    continue;
+                }
+            };
+            match diff_package(&current, &row) {
+                Err(errors) => report.status = RowStatus::Invalid(errors),
+                Ok(diff) => {
+                    report.changes = diff.changes;
+                    report.status = if report.changes.is_empty() {
+                        RowStatus::Unchanged
+                    } else if dry_run {
+                        RowStatus::Planned
+                    } else {
+                        let patched = self
+                            .package_patch()
+                            .id(current["id"].as_str().unwrap_or(&id))
+                            .custom_fields(diff.patch)
+                            .call()
+                            .await
+                            .and_then(action_result);
+                        match patched {
+                            Ok(_) => RowStatus::Applied,
+                            Err(error) => failure(error),
+                        }
+                    };
+                }
+            }
+            reports.push(report);
+        }
+        Ok(reports)
+    }
+}
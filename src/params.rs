@@ -0,0 +1,264 @@
+//! Typed values for action parameters that CKAN takes as strings.
+//!
+//! Every enum has an `Other(String)` variant for values added by extensions, and converts from
+//! `&str`/`String`, so builders accept either the enum or a plain string:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::params::{ObjectType, Role};
+//!
+//! ckan.member_create()
+//!     .id("transport")
+//!     .object("alice")
+//!     .object_type(ObjectType::User)
+//!     .capacity(Role::Editor)
+//!     .call()
+//!     .await?;
+//! ckan.organization_member_create()
+//!     .id("city-of-example")
+//!     .username("bob")
+//!     .role("admin")
+//!     .call()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{cmp::Ordering, fmt};
+
+macro_rules! string_enum {
+    ($(#[$meta: meta])* $name: ident { $($(#[$variant_meta: meta])* $variant: ident => $value: literal),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// Any value not covered by the other variants, e.g. one added by an extension.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    other => Self::Other(other.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                Self::from(value.as_str())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(Self::from(String::deserialize(deserializer)?))
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Capacity of a user in an organization or group, or on a dataset as a collaborator.
+    ///
+    /// Known roles are ordered by the permissions they grant, so `Role::Admin > Role::Member`.
+    /// `Other` roles can not be compared with other roles.
+    Role {
+        Member => "member",
+        Editor => "editor",
+        Admin => "admin",
+    }
+}
+
+impl Role {
+    /// Parse a `capacity`/`role` value as returned by CKAN, ignoring unknown roles.
+    pub fn parse(role: &str) -> Option<Self> {
+        match Self::from(role) {
+            Self::Other(_) => None,
+            role => Some(role),
+        }
+    }
+
+    fn rank(&self) -> Option<u8> {
+        match self {
+            Self::Member => Some(1),
+            Self::Editor => Some(2),
+            Self::Admin => Some(3),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl PartialOrd for Role {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        Some(self.rank()?.cmp(&other.rank()?))
+    }
+}
+
+string_enum! {
+    /// Kind of object a membership refers to.
+    ObjectType {
+        Package => "package",
+        User => "user",
+        Group => "group",
+    }
+}
+
+string_enum! {
+    /// Lifecycle state of a dataset, group or organization.
+    State {
+        Active => "active",
+        Draft => "draft",
+        Deleted => "deleted",
+    }
+}
+
+string_enum! {
+    /// Resource view plugin.
+    ViewType {
+        ImageView => "image_view",
+        WebpageView => "webpage_view",
+        DatatablesView => "datatables_view",
+        ReclineView => "recline_view",
+        TextView => "text_view",
+        PdfView => "pdf_view",
+        GeoView => "geo_view",
+        GeoJsonView => "geojson_view",
+        VideoView => "video_view",
+        AudioView => "audio_view",
+    }
+}
+
+string_enum! {
+    /// Kind of entity that owns a file.
+    OwnerType {
+        User => "user",
+        Package => "package",
+        Resource => "resource",
+        Group => "group",
+        Organization => "organization",
+    }
+}
+
+string_enum! {
+    /// Permission checked by `organization_list_for_user`.
+    Permission {
+        Read => "read",
+        CreateDataset => "create_dataset",
+        UpdateDataset => "update_dataset",
+        DeleteDataset => "delete_dataset",
+        ManageGroup => "manage_group",
+    }
+}
+
+string_enum! {
+    /// `sort` of `group_list` and `organization_list`.
+    GroupSort {
+        NameAsc => "name asc",
+        NameDesc => "name desc",
+        TitleAsc => "title asc",
+        TitleDesc => "title desc",
+        PackageCountAsc => "package_count asc",
+        PackageCountDesc => "package_count desc",
+    }
+}
+
+string_enum! {
+    /// `sort` of `package_search`.
+    PackageSort {
+        /// The default order of `package_search`.
+        Relevance => "score desc, metadata_modified desc",
+        NameAsc => "name asc",
+        NameDesc => "name desc",
+        TitleAsc => "title_string asc",
+        TitleDesc => "title_string desc",
+        MetadataModifiedAsc => "metadata_modified asc",
+        MetadataModifiedDesc => "metadata_modified desc",
+        MetadataCreatedAsc => "metadata_created asc",
+        MetadataCreatedDesc => "metadata_created desc",
+    }
+}
+
+string_enum! {
+    /// Field to order the results of `group_list`, `organization_list`, `user_list` and
+    /// `resource_search` by. Use `Other` for any other field.
+    OrderBy {
+        Name => "name",
+        Packages => "packages",
+        Created => "created",
+        Fullname => "fullname",
+    }
+}
+
+string_enum! {
+    /// `frequency` of a ckanext-harvest source.
+    HarvestFrequency {
+        Manual => "MANUAL",
+        Monthly => "MONTHLY",
+        Weekly => "WEEKLY",
+        Biweekly => "BIWEEKLY",
+        Daily => "DAILY",
+        Always => "ALWAYS",
+    }
+}
+
+string_enum! {
+    /// `status` of a ckanext-harvest job.
+    HarvestJobStatus {
+        New => "New",
+        Running => "Running",
+        Finished => "Finished",
+        Aborted => "Aborted",
+    }
+}
+
+string_enum! {
+    /// `method` of `datastore_upsert`.
+    UpsertMethod {
+        Upsert => "upsert",
+        Insert => "insert",
+        Update => "update",
+    }
+}
+
+string_enum! {
+    /// Type of a DataStore field.
+    DatastoreType {
+        Text => "text",
+        Int => "int",
+        BigInt => "bigint",
+        Numeric => "numeric",
+        Float => "float8",
+        Bool => "bool",
+        Date => "date",
+        Time => "time",
+        Timestamp => "timestamp",
+        Json => "json",
+    }
+}
@@ -0,0 +1,156 @@
+//! Membership roles, effective access of a user on a dataset and membership sync.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::permissions::Role;
+//! use std::collections::BTreeMap;
+//!
+//! let access = ckan
+//!     .dataset_access()
+//!     .user("alice")
+//!     .dataset("air-quality")
+//!     .call()
+//!     .await?;
+//! if access.can_edit() {
+//!     println!("alice can edit air-quality as {:?}", access.role());
+//! }
+//!
+//! let members = BTreeMap::from([
+//!     ("alice".to_string(), Role::Admin),
+//!     ("bob".to_string(), Role::Editor),
+//! ]);
+//! ckan.organization_members_sync()
+//!     .id("city-of-example")
+//!     .members(members)
+//!     .call()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::params::Role;
+
+use crate::{
+    CKAN, CKANError,
+    portal::{AppliedChange, GroupKind, member_changes},
+};
+use bon::bon;
+use serde_json::json;
+use std::collections::BTreeMap;
+
+/// What a user can do on a dataset, following CKAN's default authorization rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Access {
+    pub sysadmin: bool,
+    /// Whether the dataset is private.
+    pub private: bool,
+    /// Role of the user in the organization that owns the dataset.
+    pub organization_role: Option<Role>,
+    /// Capacity of the user as a collaborator of the dataset.
+    pub collaborator_role: Option<Role>,
+}
+
+impl Access {
+    /// The highest role the user has on the dataset. Sysadmins are treated as admins.
+    pub fn role(&self) -> Option<Role> {
+        if self.sysadmin {
+            return Some(Role::Admin);
+        }
+        match (&self.organization_role, &self.collaborator_role) {
+            (Some(organization), Some(collaborator)) if collaborator > organization => {
+                Some(collaborator.clone())
+            }
+            (None, collaborator) => collaborator.clone(),
+            (organization, _) => organization.clone(),
+        }
+    }
+
+    pub fn can_read(&self) -> bool {
+        !self.private || self.role().is_some()
+    }
+
+    /// Whether the user can update, patch or delete the dataset.
+    pub fn can_edit(&self) -> bool {
+        self.role() >= Some(Role::Editor)
+    }
+
+    /// Whether the user can add or remove collaborators of the dataset.
+    pub fn can_manage_collaborators(&self) -> bool {
+        self.sysadmin
+            || self.organization_role == Some(Role::Admin)
+            || self.collaborator_role == Some(Role::Admin)
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// Compute the effective `Access` of `user` on `dataset` from the user's sysadmin flag, their
+    /// role in the dataset's organization and their collaborator capacity.
+    #[builder(on(String, into))]
+    pub async fn dataset_access(&self, user: String, dataset: String) -> Result<Access, CKANError> {
+        let user = self.call_action("user_show", json!({ "id": user })).await?;
+        let user_id = user["id"].as_str().unwrap_or_default();
+        let dataset = self
+            .call_action("package_show", json!({ "id": dataset }))
+            .await?;
+
+        let organization_role = match dataset["owner_org"].as_str() {
+            Some(owner_org) => self
+                .call_action(
+                    "member_list",
+                    json!({ "id": owner_org, "object_type": "user" }),
+                )
+                .await?
+                .as_array()
+                .into_iter()
+                .flatten()
+                // Each member is an `[id, type, capacity]` triple.
+                .find(|member| member[0] == user_id)
+                .and_then(|member| Role::parse(member[2].as_str()?)),
+            None => None,
+        };
+        // Collaborators may be disabled on the instance, which is reported as an error.
+        let collaborator_role = match self
+            .call_action("package_collaborator_list", json!({ "id": dataset["id"] }))
+            .await
+        {
+            Ok(collaborators) => collaborators
+                .as_array()
+                .into_iter()
+                .flatten()
+                .find(|collaborator| collaborator["user_id"] == user_id)
+                .and_then(|collaborator| Role::parse(collaborator["capacity"].as_str()?)),
+            Err(CKANError::ActionError(_)) => None,
+            Err(error) => return Err(error),
+        };
+
+        Ok(Access {
+            sysadmin: user["sysadmin"].as_bool().unwrap_or(false),
+            private: dataset["private"].as_bool().unwrap_or(false),
+            organization_role,
+            collaborator_role,
+        })
+    }
+
+    /// Make the members of organization `id` match `members` (usernames mapped to roles).
+    ///
+    /// Users missing from `members` are removed unless `remove_missing(false)` is set. Returns
+    /// the result of every membership change that was needed.
+    #[builder(on(String, into))]
+    pub async fn organization_members_sync(
+        &self,
+        id: String,
+        members: BTreeMap<String, Role>,
+        remove_missing: Option<bool>,
+    ) -> Result<Vec<AppliedChange>, CKANError> {
+        let current = self.member_roles(&id).await?;
+        let changes = member_changes(
+            GroupKind::Organization,
+            &id,
+            &current,
+            &members,
+            remove_missing.unwrap_or(true),
+        );
+        Ok(self.apply_changes(changes).await)
+    }
+}
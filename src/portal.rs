@@ -0,0 +1,771 @@
+//! Declarative portal configuration: describe organizations, groups, memberships, vocabularies
+//! and licenses in YAML or TOML, compute a plan against the live instance and apply it.
+//!
+//! ```yaml
+//! prune: false
+//! licenses: [cc-by, odc-odbl]
+//! organizations:
+//!   - name: city-of-example
+//!     title: City of Example
+//!     members:
+//!       alice: admin
+//!       bob: editor
+//! groups:
+//!   - name: transport
+//!     title: Transport
+//! vocabularies:
+//!   - name: topics
+//!     tags: [air, water]
+//! ```
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::portal::PortalConfig;
+//!
+//! let config = PortalConfig::from_path("portal.yaml")?;
+//! let plan = ckan.portal_plan().config(&config).call().await?;
+//! print!("{plan}");
+//! for applied in ckan.portal_apply().plan(&plan).call().await {
+//!     if let Err(error) = applied.result {
+//!         eprintln!("{}: {error}", applied.change);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Printing a plan without applying it doubles as a drift report.
+
+use crate::{
+    CKAN, CKANError, action_result,
+    ensure::is_subset,
+    params::{ObjectType, Role},
+};
+use bon::bon;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    path::Path,
+};
+
+const PAGE_SIZE: usize = 1000;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PortalConfig {
+    /// Delete organizations, groups and vocabularies that are not part of the configuration.
+    /// Only the sections the configuration has are pruned.
+    #[serde(default)]
+    pub prune: bool,
+    /// Ids of the licenses the portal is expected to offer. Licenses are configured on the
+    /// server, so missing ones are only reported.
+    #[serde(default)]
+    pub licenses: Vec<String>,
+    /// The organizations of the portal. When left out, organizations are not managed.
+    pub organizations: Option<Vec<GroupSpec>>,
+    /// The groups of the portal. When left out, groups are not managed.
+    pub groups: Option<Vec<GroupSpec>>,
+    /// The tag vocabularies of the portal. When left out, vocabularies are not managed.
+    pub vocabularies: Option<Vec<VocabularySpec>>,
+}
+
+/// Desired state of an organization or a group.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GroupSpec {
+    pub name: String,
+    /// Usernames mapped to their role. When given, members not listed are removed. When left
+    /// out, memberships are not managed.
+    pub members: Option<BTreeMap<String, Role>>,
+    /// Any other field accepted by `organization_create`/`group_create`, e.g. `title`.
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VocabularySpec {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl PortalConfig {
+    pub fn from_yaml_str(config: &str) -> Result<Self, CKANError> {
+        Ok(serde_yaml::from_str(config)?)
+    }
+
+    pub fn from_toml_str(config: &str) -> Result<Self, CKANError> {
+        Ok(toml::from_str(config)?)
+    }
+
+    /// Load a `.toml` file, or a YAML file for any other extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CKANError> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml_str(&config),
+            _ => Self::from_yaml_str(&config),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupKind {
+    Organization,
+    Group,
+}
+
+impl GroupKind {
+    /// Prefix of the CKAN actions for this kind, e.g. `organization` in `organization_create`.
+    pub fn action_prefix(&self) -> &'static str {
+        match self {
+            Self::Organization => "organization",
+            Self::Group => "group",
+        }
+    }
+}
+
+/// A single step of a `Plan`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Create {
+        kind: GroupKind,
+        name: String,
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+    Update {
+        kind: GroupKind,
+        name: String,
+        /// Only the fields that differ from the live instance.
+        fields: serde_json::Map<String, serde_json::Value>,
+    },
+    Delete {
+        kind: GroupKind,
+        name: String,
+    },
+    SetMember {
+        kind: GroupKind,
+        group: String,
+        username: String,
+        role: Role,
+        /// The current role, if the user is already a member.
+        previous: Option<Role>,
+    },
+    RemoveMember {
+        kind: GroupKind,
+        group: String,
+        username: String,
+    },
+    /// Remove the admin membership CKAN gives the user who creates an organization or group,
+    /// unless that user is in `keep`. The user is only known once the group exists.
+    RemoveCreator {
+        kind: GroupKind,
+        group: String,
+        keep: Vec<String>,
+    },
+    CreateVocabulary {
+        name: String,
+        tags: Vec<String>,
+    },
+    UpdateVocabulary {
+        id: String,
+        name: String,
+        tags: Vec<String>,
+    },
+    DeleteVocabulary {
+        id: String,
+        name: String,
+    },
+}
+
+impl Change {
+    /// The CKAN action and body that apply this change. The body of `RemoveCreator` has no
+    /// `username`, which is only known once the group exists.
+    pub fn action(&self) -> (String, serde_json::Value) {
+        match self {
+            Self::Create { kind, name, fields } => {
+                let mut body = fields.clone();
+                body.insert("name".to_string(), json!(name));
+                (format!("{}_create", kind.action_prefix()), body.into())
+            }
+            Self::Update { kind, name, fields } => {
+                let mut body = fields.clone();
+                body.insert("id".to_string(), json!(name));
+                (format!("{}_patch", kind.action_prefix()), body.into())
+            }
+            Self::Delete { kind, name } => (
+                format!("{}_delete", kind.action_prefix()),
+                json!({ "id": name }),
+            ),
+            Self::SetMember {
+                kind,
+                group,
+                username,
+                role,
+                ..
+            } => (
+                format!("{}_member_create", kind.action_prefix()),
+                json!({ "id": group, "username": username, "role": role }),
+            ),
+            Self::RemoveMember {
+                kind,
+                group,
+                username,
+            } => (
+                format!("{}_member_delete", kind.action_prefix()),
+                json!({ "id": group, "username": username }),
+            ),
+            Self::RemoveCreator { kind, group, .. } => (
+                format!("{}_member_delete", kind.action_prefix()),
+                json!({ "id": group }),
+            ),
+            Self::CreateVocabulary { name, tags } => (
+                "vocabulary_create".to_string(),
+                json!({ "name": name, "tags": tag_objects(tags) }),
+            ),
+            Self::UpdateVocabulary { id, name, tags } => (
+                "vocabulary_update".to_string(),
+                json!({ "id": id, "name": name, "tags": tag_objects(tags) }),
+            ),
+            Self::DeleteVocabulary { id, .. } => {
+                ("vocabulary_delete".to_string(), json!({ "id": id }))
+            }
+        }
+    }
+}
+
+fn tag_objects(tags: &[String]) -> Vec<serde_json::Value> {
+    tags.iter().map(|tag| json!({ "name": tag })).collect()
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Create { kind, name, .. } => write!(f, "+ {} {name}", kind.action_prefix()),
+            Self::Update { kind, name, fields } => {
+                let fields: Vec<&str> = fields.keys().map(String::as_str).collect();
+                write!(
+                    f,
+                    "~ {} {name} ({})",
+                    kind.action_prefix(),
+                    fields.join(", ")
+                )
+            }
+            Self::Delete { kind, name } => write!(f, "- {} {name}", kind.action_prefix()),
+            Self::SetMember {
+                kind,
+                group,
+                username,
+                role,
+                previous: None,
+            } => write!(
+                f,
+                "+ member {username} of {} {group} as {role}",
+                kind.action_prefix()
+            ),
+            Self::SetMember {
+                kind,
+                group,
+                username,
+                role,
+                previous: Some(previous),
+            } => write!(
+                f,
+                "~ member {username} of {} {group} ({previous} -> {role})",
+                kind.action_prefix()
+            ),
+            Self::RemoveMember {
+                kind,
+                group,
+                username,
+            } => write!(f, "- member {username} of {} {group}", kind.action_prefix()),
+            Self::RemoveCreator { kind, group, .. } => write!(
+                f,
+                "- creator of {} {group} unless a declared member",
+                kind.action_prefix()
+            ),
+            Self::CreateVocabulary { name, tags } => {
+                write!(f, "+ vocabulary {name} [{}]", tags.join(", "))
+            }
+            Self::UpdateVocabulary { name, tags, .. } => {
+                write!(f, "~ vocabulary {name} [{}]", tags.join(", "))
+            }
+            Self::DeleteVocabulary { name, .. } => write!(f, "- vocabulary {name}"),
+        }
+    }
+}
+
+/// The changes needed for the live instance to match a `PortalConfig`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+    /// Licenses listed in the configuration that the server does not offer. These can not be
+    /// fixed through the API and are not part of `changes`.
+    pub missing_licenses: Vec<String>,
+}
+
+impl Plan {
+    /// Whether the live instance already matches the configuration.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.missing_licenses.is_empty()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{change}")?;
+        }
+        for license in &self.missing_licenses {
+            writeln!(f, "! license {license} is not available")?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of applying one change of a `Plan`.
+#[derive(Debug)]
+pub struct AppliedChange {
+    pub change: Change,
+    pub result: Result<serde_json::Value, CKANError>,
+}
+
+/// The membership changes that turn `current` into `desired`.
+pub(crate) fn member_changes(
+    kind: GroupKind,
+    group: &str,
+    current: &BTreeMap<String, Role>,
+    desired: &BTreeMap<String, Role>,
+    remove_missing: bool,
+) -> Vec<Change> {
+    let mut changes = Vec::new();
+    for (username, role) in desired {
+        let previous = current.get(username);
+        if previous != Some(role) {
+            changes.push(Change::SetMember {
+                kind,
+                group: group.to_string(),
+                username: username.clone(),
+                role: role.clone(),
+                previous: previous.cloned(),
+            });
+        }
+    }
+    if remove_missing {
+        for username in current.keys().filter(|name| !desired.contains_key(*name)) {
+            changes.push(Change::RemoveMember {
+                kind,
+                group: group.to_string(),
+                username: username.clone(),
+            });
+        }
+    }
+    changes
+}
+
+impl CKAN {
+    async fn apply_change(&self, change: &Change) -> Result<serde_json::Value, CKANError> {
+        use GroupKind::{Group, Organization};
+        if let Change::RemoveCreator { kind, group, keep } = change {
+            let mut removed = Vec::new();
+            for username in self.member_roles(group).await?.into_keys() {
+                if keep.contains(&username) {
+                    continue;
+                }
+                let response = match kind {
+                    Organization => {
+                        self.organization_member_delete()
+                            .id(group)
+                            .username(&username)
+                            .call()
+                            .await
+                    }
+                    Group => {
+                        self.group_member_delete()
+                            .id(group)
+                            .username(&username)
+                            .call()
+                            .await
+                    }
+                };
+                action_result(response?)?;
+                removed.push(username);
+            }
+            return Ok(json!(removed));
+        }
+        let response = match change.clone() {
+            Change::Create {
+                kind: Organization,
+                name,
+                fields,
+            } => {
+                self.organization_create()
+                    .name(name)
+                    .custom_fields(fields.into())
+                    .call()
+                    .await
+            }
+            Change::Create {
+                kind: Group,
+                name,
+                fields,
+            } => {
+                self.group_create()
+                    .name(name)
+                    .custom_fields(fields.into())
+                    .call()
+                    .await
+            }
+            Change::Update {
+                kind: Organization,
+                name,
+                fields,
+            } => {
+                self.organization_patch()
+                    .id(name)
+                    .custom_fields(fields.into())
+                    .call()
+                    .await
+            }
+            Change::Update {
+                kind: Group,
+                name,
+                fields,
+            } => {
+                self.group_patch()
+                    .id(name)
+                    .custom_fields(fields.into())
+                    .call()
+                    .await
+            }
+            Change::Delete {
+                kind: Organization,
+                name,
+            } => self.organization_delete().id(name).call().await,
+            Change::Delete { kind: Group, name } => self.group_delete().id(name).call().await,
+            Change::SetMember {
+                kind: Organization,
+                group,
+                username,
+                role,
+                ..
+            } => {
+                self.organization_member_create()
+                    .id(group)
+                    .username(username)
+                    .role(role)
+                    .call()
+                    .await
+            }
+            Change::SetMember {
+                kind: Group,
+                group,
+                username,
+                role,
+                ..
+            } => {
+                self.group_member_create()
+                    .id(group)
+                    .username(username)
+                    .role(role)
+                    .call()
+                    .await
+            }
+            Change::RemoveMember {
+                kind: Organization,
+                group,
+                username,
+            } => {
+                self.organization_member_delete()
+                    .id(group)
+                    .username(username)
+                    .call()
+                    .await
+            }
+            Change::RemoveMember {
+                kind: Group,
+                group,
+                username,
+            } => {
+                self.group_member_delete()
+                    .id(group)
+                    .username(username)
+                    .call()
+                    .await
+            }
+            Change::CreateVocabulary { name, tags } => {
+                self.vocabulary_create()
+                    .name(name)
+                    .tags(tag_objects(&tags))
+                    .call()
+                    .await
+            }
+            Change::UpdateVocabulary { id, name, tags } => {
+                self.vocabulary_update()
+                    .id(id)
+                    .name(name)
+                    .tags(tag_objects(&tags))
+                    .call()
+                    .await
+            }
+            Change::DeleteVocabulary { id, .. } => self.vocabulary_delete().id(id).call().await,
+            Change::RemoveCreator { .. } => unreachable!("applied above"),
+        };
+        action_result(response?)
+    }
+
+    pub(crate) async fn apply_changes(&self, changes: Vec<Change>) -> Vec<AppliedChange> {
+        let mut applied = Vec::new();
+        for change in changes {
+            applied.push(AppliedChange {
+                result: self.apply_change(&change).await,
+                change,
+            });
+        }
+        applied
+    }
+
+    /// Usernames mapped to their capacity in the organization or group `id`, from `member_list`.
+    ///
+    /// `member_list` only returns user ids, which are resolved with a single `user_list`. Members
+    /// it does not list keep their id, which the member actions accept as well.
+    pub(crate) async fn member_roles(&self, id: &str) -> Result<BTreeMap<String, Role>, CKANError> {
+        let members = action_result(
+            self.member_list()
+                .id(id)
+                .object_type(ObjectType::User)
+                .call()
+                .await?,
+        )?;
+        let members: Vec<(String, String, String)> = serde_json::from_value(members)?;
+        if members.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+        let users = action_result(self.user_list().all_fields(true).call().await?)?;
+        let names: BTreeMap<&str, &str> = users
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|user| Some((user["id"].as_str()?, user["name"].as_str()?)))
+            .collect();
+        Ok(members
+            .into_iter()
+            .map(|(user_id, _, capacity)| {
+                let username = names
+                    .get(user_id.as_str())
+                    .map_or(user_id.clone(), |name| name.to_string());
+                (username, Role::from(capacity))
+            })
+            .collect())
+    }
+
+    async fn list_all_names(&self, kind: GroupKind) -> Result<Vec<String>, CKANError> {
+        let mut names = Vec::new();
+        loop {
+            let (limit, offset) = (PAGE_SIZE as i32, names.len() as i32);
+            let page = match kind {
+                GroupKind::Organization => {
+                    self.organization_list()
+                        .limit(limit)
+                        .offset(offset)
+                        .call()
+                        .await
+                }
+                GroupKind::Group => self.group_list().limit(limit).offset(offset).call().await,
+            };
+            let page = action_result(page?)?;
+            let page: Vec<String> = serde_json::from_value(page)?;
+            let done = page.len() < PAGE_SIZE;
+            names.extend(page);
+            if done {
+                return Ok(names);
+            }
+        }
+    }
+
+    async fn plan_groups(
+        &self,
+        kind: GroupKind,
+        specs: Option<&[GroupSpec]>,
+        prune: bool,
+        plan: &mut Plan,
+    ) -> Result<(), CKANError> {
+        let Some(specs) = specs else {
+            return Ok(());
+        };
+        let existing = self.list_all_names(kind).await?;
+        for spec in specs {
+            let mut members = BTreeMap::new();
+            if existing.contains(&spec.name) {
+                let current = match kind {
+                    GroupKind::Organization => {
+                        self.organization_show()
+                            .id(&spec.name)
+                            .include_datasets(false)
+                            .call()
+                            .await
+                    }
+                    GroupKind::Group => {
+                        self.group_show()
+                            .id(&spec.name)
+                            .include_datasets(false)
+                            .call()
+                            .await
+                    }
+                };
+                let current = action_result(current?)?;
+                let fields: serde_json::Map<_, _> = spec
+                    .fields
+                    .iter()
+                    .filter(|(field, value)| !is_subset(value, &current[field.as_str()]))
+                    .map(|(field, value)| (field.to_owned(), value.to_owned()))
+                    .collect();
+                if !fields.is_empty() {
+                    plan.changes.push(Change::Update {
+                        kind,
+                        name: spec.name.clone(),
+                        fields,
+                    });
+                }
+                if spec.members.is_some() {
+                    members = self.member_roles(&spec.name).await?;
+                }
+            } else {
+                plan.changes.push(Change::Create {
+                    kind,
+                    name: spec.name.clone(),
+                    fields: spec.fields.clone(),
+                });
+                if let Some(desired) = &spec.members {
+                    plan.changes.push(Change::RemoveCreator {
+                        kind,
+                        group: spec.name.clone(),
+                        keep: desired.keys().cloned().collect(),
+                    });
+                }
+            }
+
+            if let Some(desired) = &spec.members {
+                plan.changes
+                    .extend(member_changes(kind, &spec.name, &members, desired, true));
+            }
+        }
+        if prune {
+            let declared: BTreeSet<&String> = specs.iter().map(|spec| &spec.name).collect();
+            for name in existing.iter().filter(|name| !declared.contains(name)) {
+                plan.changes.push(Change::Delete {
+                    kind,
+                    name: name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    async fn plan_vocabularies(
+        &self,
+        specs: Option<&[VocabularySpec]>,
+        prune: bool,
+        plan: &mut Plan,
+    ) -> Result<(), CKANError> {
+        let Some(specs) = specs else {
+            return Ok(());
+        };
+        let existing = self.vocabulary_list().await.and_then(action_result)?;
+        let existing = existing.as_array().cloned().unwrap_or_default();
+        for spec in specs {
+            let current = existing
+                .iter()
+                .find(|vocabulary| vocabulary["name"] == spec.name.as_str());
+            let desired_tags: BTreeSet<&str> = spec.tags.iter().map(String::as_str).collect();
+            match current {
+                None => plan.changes.push(Change::CreateVocabulary {
+                    name: spec.name.clone(),
+                    tags: spec.tags.clone(),
+                }),
+                Some(current) => {
+                    let current_tags: BTreeSet<&str> = current["tags"]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|tag| tag["name"].as_str())
+                        .collect();
+                    if current_tags != desired_tags {
+                        plan.changes.push(Change::UpdateVocabulary {
+                            id: current["id"].as_str().unwrap_or_default().to_string(),
+                            name: spec.name.clone(),
+                            tags: spec.tags.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        if prune {
+            for vocabulary in &existing {
+                let name = vocabulary["name"].as_str().unwrap_or_default();
+                if !specs.iter().any(|spec| spec.name == name) {
+                    plan.changes.push(Change::DeleteVocabulary {
+                        id: vocabulary["id"].as_str().unwrap_or_default().to_string(),
+                        name: name.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[bon]
+impl CKAN {
+    /// Compare `config` with the live instance and list the changes needed to match it.
+    #[builder]
+    pub async fn portal_plan(&self, config: &PortalConfig) -> Result<Plan, CKANError> {
+        let mut plan = Plan::default();
+        self.plan_groups(
+            GroupKind::Organization,
+            config.organizations.as_deref(),
+            config.prune,
+            &mut plan,
+        )
+        .await?;
+        self.plan_groups(
+            GroupKind::Group,
+            config.groups.as_deref(),
+            config.prune,
+            &mut plan,
+        )
+        .await?;
+        self.plan_vocabularies(config.vocabularies.as_deref(), config.prune, &mut plan)
+            .await?;
+
+        let licenses = action_result(self.license_list().call().await?)?;
+        let available: BTreeSet<&str> = licenses
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|license| license["id"].as_str())
+            .collect();
+        plan.missing_licenses = config
+            .licenses
+            .iter()
+            .filter(|license| !available.contains(license.as_str()))
+            .cloned()
+            .collect();
+
+        // Remove memberships before deleting anything, and create before adding members.
+        plan.changes.sort_by_key(|change| match change {
+            Change::Create { .. } | Change::CreateVocabulary { .. } => 0,
+            Change::Update { .. } | Change::UpdateVocabulary { .. } => 1,
+            Change::SetMember { .. } => 2,
+            Change::RemoveMember { .. } | Change::RemoveCreator { .. } => 3,
+            Change::Delete { .. } | Change::DeleteVocabulary { .. } => 4,
+        });
+        Ok(plan)
+    }
+
+    /// Apply every change of `plan` in order. A failing change does not stop the others.
+    #[builder]
+    pub async fn portal_apply(&self, plan: &Plan) -> Vec<AppliedChange> {
+        self.apply_changes(plan.changes.clone()).await
+    }
+}
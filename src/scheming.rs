@@ -0,0 +1,331 @@
+//! Client-side validation of package and resource payloads against a ckanext-scheming schema.
+//!
+//! Build a `CKAN` with a `Schema` and `package_create`, `package_update`, `package_patch` and the
+//! `resource_*` equivalents are checked before being sent. Invalid payloads fail with a
+//! `CKANError::ActionError` holding the field errors in the shape CKAN uses for its own
+//! validation errors, e.g. `{"__type": "Validation Error", "title": ["Missing value"]}`.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::scheming::Schema;
+//!
+//! // Fetch the schema from `scheming_dataset_schema_show`...
+//! let schema = ckan.scheming_schema().call().await?;
+//! // ...or load it from the file the instance uses.
+//! let schema = Schema::from_path("schemas/dataset.yaml")?;
+//!
+//! let ckan = ckanaction::CKAN::builder()
+//!     .url("https://demo.ckan.org")
+//!     .schema(schema)
+//!     .build();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Required fields, `choices`, the `pattern` in `form_attrs` and the `not_empty`, `int_validator`,
+//! `isodate`, `email_validator` and `name_validator` validators (or the presets using them) are
+//! checked. Anything else is left to the server.
+
+use crate::{CKAN, CKANError};
+use bon::bon;
+use regex::Regex;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Map, Value, json};
+use std::{collections::BTreeMap, path::Path, sync::LazyLock};
+
+static NAME: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[a-z0-9_-]{2,100}$").unwrap());
+static ISODATE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}").unwrap());
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap());
+
+const NAME_ERROR: &str =
+    "Must be purely lowercase alphanumeric (ascii) characters and these symbols: -_";
+
+/// A ckanext-scheming dataset schema.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Schema {
+    pub scheming_version: Option<u32>,
+    pub dataset_type: String,
+    pub about: Option<String>,
+    #[serde(default)]
+    pub dataset_fields: Vec<Field>,
+    #[serde(default)]
+    pub resource_fields: Vec<Field>,
+    #[serde(flatten)]
+    pub custom_fields: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Field {
+    pub field_name: String,
+    /// A string, or a map of language codes to strings.
+    pub label: Option<Value>,
+    #[serde(default)]
+    pub required: bool,
+    pub preset: Option<String>,
+    /// Space-separated validator names, e.g. `scheming_required unicode_safe`.
+    pub validators: Option<String>,
+    pub choices: Option<Vec<Choice>>,
+    pub form_attrs: Option<Map<String, Value>>,
+    #[serde(flatten)]
+    pub custom_fields: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Choice {
+    pub value: String,
+    pub label: Option<Value>,
+}
+
+/// Errors per field name. Errors of the resources of a package are listed by position.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationErrors {
+    pub fields: BTreeMap<String, Vec<String>>,
+    pub resources: Vec<BTreeMap<String, Vec<String>>>,
+}
+
+impl ValidationErrors {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.resources.iter().all(BTreeMap::is_empty)
+    }
+
+    /// The errors as CKAN reports them in the `error` of a failed action.
+    pub fn to_value(&self) -> Value {
+        let mut error = json!({ "__type": "Validation Error" });
+        for (field, messages) in &self.fields {
+            error[field] = json!(messages);
+        }
+        if self.resources.iter().any(|resource| !resource.is_empty()) {
+            error["resources"] = json!(self.resources);
+        }
+        error
+    }
+}
+
+impl Field {
+    fn validator_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .validators
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|validator| validator.split('(').next().unwrap_or_default())
+            .collect();
+        // Presets fill in `validators` when the schema is expanded, but not in local files.
+        names.extend(match self.preset.as_deref() {
+            Some("dataset_slug") => &["name_validator"][..],
+            Some("date" | "datetime" | "datetime_tz") => &["isodate"][..],
+            _ => &[],
+        });
+        names
+    }
+
+    fn is_required(&self) -> bool {
+        self.required || self.validator_names().contains(&"not_empty")
+    }
+
+    /// Error messages for `value`, following the messages of the matching CKAN validators.
+    fn validate(&self, value: Option<&Value>, partial: bool) -> Vec<String> {
+        let value = match value {
+            None if partial => return vec![],
+            Some(value) if !is_empty(value) => value,
+            _ if self.is_required() => return vec!["Missing value".to_string()],
+            _ => return vec![],
+        };
+        let text = match value {
+            Value::String(text) => text.clone(),
+            value => value.to_string(),
+        };
+        let mut errors = vec![];
+        if let Some(choices) = &self.choices {
+            let values = match value {
+                Value::Array(values) => values.iter().collect(),
+                value => vec![value],
+            };
+            for value in values {
+                let value = value
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or(value.to_string());
+                if !choices.iter().any(|choice| choice.value == value) {
+                    errors.push(format!("unexpected choice \"{value}\""));
+                }
+            }
+        }
+        if let Some(pattern) = self
+            .form_attrs
+            .as_ref()
+            .and_then(|attrs| attrs.get("pattern"))
+            .and_then(Value::as_str)
+            && let Ok(regex) = Regex::new(&format!("^(?:{pattern})$"))
+            && !regex.is_match(&text)
+        {
+            errors.push(format!("Value does not match the pattern \"{pattern}\""));
+        }
+        for validator in self.validator_names() {
+            match validator {
+                "int_validator" if text.parse::<i64>().is_err() => {
+                    errors.push("Invalid integer".to_string())
+                }
+                "isodate" | "scheming_isodatetime" | "scheming_isodatetime_tz"
+                    if !ISODATE.is_match(&text) =>
+                {
+                    errors.push("Date format incorrect".to_string())
+                }
+                "email_validator" if !EMAIL.is_match(&text) => {
+                    errors.push(format!("Email {text} is not a valid format"))
+                }
+                "name_validator" | "package_name_validator" if !NAME.is_match(&text) => {
+                    errors.push(NAME_ERROR.to_string())
+                }
+                _ => {}
+            }
+        }
+        errors
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        Value::Array(values) => values.is_empty(),
+        _ => false,
+    }
+}
+
+fn validate_fields(
+    fields: &[Field],
+    payload: &Value,
+    partial: bool,
+) -> BTreeMap<String, Vec<String>> {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let errors = field.validate(payload.get(&field.field_name), partial);
+            (!errors.is_empty()).then(|| (field.field_name.clone(), errors))
+        })
+        .collect()
+}
+
+impl Schema {
+    /// Parse the `result` of `scheming_dataset_schema_show`.
+    pub fn from_value(schema: Value) -> Result<Self, CKANError> {
+        Ok(serde_json::from_value(schema)?)
+    }
+
+    pub fn from_json_str(schema: &str) -> Result<Self, CKANError> {
+        Ok(serde_json::from_str(schema)?)
+    }
+
+    pub fn from_yaml_str(schema: &str) -> Result<Self, CKANError> {
+        Ok(serde_yaml::from_str(schema)?)
+    }
+
+    /// Load a `.json` file, or a YAML file for any other extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CKANError> {
+        let path = path.as_ref();
+        let schema = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json_str(&schema),
+            _ => Self::from_yaml_str(&schema),
+        }
+    }
+
+    /// Validate a `package_create`/`package_update` payload, or a `package_patch` payload if
+    /// `partial` is set, in which case only the fields present are checked.
+    pub fn validate_package(&self, package: &Value, partial: bool) -> ValidationErrors {
+        let resources = package
+            .get("resources")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .map(|resource| validate_fields(&self.resource_fields, resource, false))
+            .collect();
+        ValidationErrors {
+            fields: validate_fields(&self.dataset_fields, package, partial),
+            resources,
+        }
+    }
+
+    /// Validate a `resource_create`/`resource_update` payload, or a `resource_patch` payload if
+    /// `partial` is set.
+    pub fn validate_resource(&self, resource: &Value, partial: bool) -> ValidationErrors {
+        ValidationErrors {
+            fields: validate_fields(&self.resource_fields, resource, partial),
+            resources: vec![],
+        }
+    }
+
+    /// Validate the body of `action`. Actions other than package and resource
+    /// create/update/patch, and packages of another dataset type, have no errors.
+    pub fn validate_action(&self, action: &str, body: &Value) -> ValidationErrors {
+        let other_type = body
+            .get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|dataset_type| dataset_type != self.dataset_type);
+        match action {
+            "package_create" | "package_update" if !other_type => {
+                self.validate_package(body, false)
+            }
+            "package_patch" if !other_type => self.validate_package(body, true),
+            "resource_create" | "resource_update" => self.validate_resource(body, false),
+            "resource_patch" => self.validate_resource(body, true),
+            _ => ValidationErrors::default(),
+        }
+    }
+}
+
+/// Custom fields of a dataset or resource, as generated by the `codegen` module.
+pub trait SchemingFields: Serialize + DeserializeOwned {
+    /// Read the fields from the `custom_fields` of a typed `Package` or `Resource`.
+    fn from_custom_fields(custom_fields: &Map<String, Value>) -> Result<Self, CKANError> {
+        Ok(serde_json::from_value(Value::Object(
+            custom_fields.clone(),
+        ))?)
+    }
+
+    /// The fields as the `custom_fields` of `package_create` and friends.
+    fn to_custom_fields(&self) -> Result<Value, CKANError> {
+        Ok(serde_json::to_value(self)?)
+    }
+}
+
+/// Deserialize a number or boolean that CKAN may return as a string, as it does for the fields
+/// it stores as extras. An empty string is `None`. Used by the code from the `codegen` module.
+pub fn string_or_value<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    use serde::de::Error;
+    let value = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(text)) if text.trim().is_empty() => return Ok(None),
+        // Python writes booleans as `True` and `False`.
+        Some(Value::String(text)) => serde_json::from_str(&text.trim().to_lowercase())
+            .map_err(|_| D::Error::custom(format!("{text:?} is not a number or boolean")))?,
+        Some(value) => value,
+    };
+    serde_json::from_value(value)
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
+#[bon]
+impl CKAN {
+    /// Fetch the expanded schema of `_type` (`dataset` by default) from
+    /// `scheming_dataset_schema_show`.
+    #[builder(on(String, into))]
+    pub async fn scheming_schema(&self, _type: Option<String>) -> Result<Schema, CKANError> {
+        self.require_extension("scheming_datasets", "scheming_dataset_schema_show")
+            .await?;
+        let schema = self
+            .call_action(
+                "scheming_dataset_schema_show",
+                json!({ "type": _type.unwrap_or("dataset".to_string()), "expanded": true }),
+            )
+            .await?;
+        Schema::from_value(schema)
+    }
+}
@@ -0,0 +1,361 @@
+//! Typed builder for the Solr `q`, `fq` and `fq_list` parameters of `package_search`.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::search::{Query, SearchQuery};
+//!
+//! let query = SearchQuery::new()
+//!     .text("air quality")
+//!     .filter(Query::organization("city-of-example"))
+//!     .filter(Query::tag("health").or(Query::tag("environment")))
+//!     .filter(Query::at_least("metadata_modified", "2024-01-01T00:00:00Z"));
+//! let result = ckan
+//!     .package_search()
+//!     .maybe_q(query.q())
+//!     .maybe_fq_list(query.fq_list())
+//!     .call()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Responses can be parsed into a `SearchResult` to get typed packages and facets, and a chosen
+//! facet item can be turned back into a filter to drill down:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::search::{SearchQuery, SearchResult};
+//!
+//! let response = ckan
+//!     .package_search()
+//!     .facet_field(vec!["tags".to_string(), "res_format".to_string()])
+//!     .call()
+//!     .await?;
+//! let result = SearchResult::from_response(response)?;
+//! let top_tag = &result.facets["tags"][0];
+//! let query = SearchQuery::new().drill_down("tags", top_tag);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKANError, action_result, types::Package};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{collections::HashMap, fmt};
+
+const SPECIAL_CHARACTERS: &str = "+-&|!(){}[]^\"~*?:\\/";
+
+/// Backslash-escape every Lucene special character and whitespace in `value`.
+pub fn escape(value: &str) -> String {
+    escape_except(value, "")
+}
+
+fn escape_except(value: &str, keep: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if !keep.contains(c) && (SPECIAL_CHARACTERS.contains(c) || c.is_whitespace()) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Quote `value` as a Lucene phrase.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// A single Solr query clause. Values are escaped when the query is rendered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    /// Matches every document (`*:*`).
+    All,
+    /// Free text matched against the default search fields.
+    Text(String),
+    /// `field:"value"`
+    Equals {
+        field: String,
+        value: String,
+    },
+    /// `field:[from TO to]`, where a missing bound is open.
+    Range {
+        field: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    /// `field:pattern`, where `*` and `?` in `pattern` are kept as wildcards.
+    Wildcard {
+        field: String,
+        pattern: String,
+    },
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+    /// Inserted as-is without escaping.
+    Raw(String),
+}
+
+impl Query {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn field(field: impl Into<String>, value: impl Into<String>) -> Self {
+        Self::Equals {
+            field: field.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn tag(name: impl Into<String>) -> Self {
+        Self::field("tags", name)
+    }
+
+    pub fn organization(name: impl Into<String>) -> Self {
+        Self::field("organization", name)
+    }
+
+    pub fn group(name: impl Into<String>) -> Self {
+        Self::field("groups", name)
+    }
+
+    pub fn res_format(format: impl Into<String>) -> Self {
+        Self::field("res_format", format)
+    }
+
+    pub fn wildcard(field: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self::Wildcard {
+            field: field.into(),
+            pattern: pattern.into(),
+        }
+    }
+
+    /// Inclusive range on a date or number field, e.g. `Query::between("num_resources", 1, 5)`.
+    pub fn between(field: impl Into<String>, from: impl ToString, to: impl ToString) -> Self {
+        Self::Range {
+            field: field.into(),
+            from: Some(from.to_string()),
+            to: Some(to.to_string()),
+        }
+    }
+
+    pub fn at_least(field: impl Into<String>, from: impl ToString) -> Self {
+        Self::Range {
+            field: field.into(),
+            from: Some(from.to_string()),
+            to: None,
+        }
+    }
+
+    pub fn at_most(field: impl Into<String>, to: impl ToString) -> Self {
+        Self::Range {
+            field: field.into(),
+            from: None,
+            to: Some(to.to_string()),
+        }
+    }
+
+    pub fn raw(query: impl Into<String>) -> Self {
+        Self::Raw(query.into())
+    }
+
+    pub fn all_of(queries: impl IntoIterator<Item = Query>) -> Self {
+        Self::And(queries.into_iter().collect())
+    }
+
+    pub fn any_of(queries: impl IntoIterator<Item = Query>) -> Self {
+        Self::Or(queries.into_iter().collect())
+    }
+
+    pub fn and(self, other: Query) -> Self {
+        match self {
+            Self::And(mut queries) => {
+                queries.push(other);
+                Self::And(queries)
+            }
+            query => Self::And(vec![query, other]),
+        }
+    }
+
+    pub fn or(self, other: Query) -> Self {
+        match self {
+            Self::Or(mut queries) => {
+                queries.push(other);
+                Self::Or(queries)
+            }
+            query => Self::Or(vec![query, other]),
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// Join `queries` with `operator`. No queries render as `empty`: everything for `AND` and
+/// nothing for `OR`.
+fn join(
+    f: &mut fmt::Formatter<'_>,
+    queries: &[Query],
+    operator: &str,
+    empty: Query,
+) -> fmt::Result {
+    match queries {
+        [] => write!(f, "{empty}"),
+        [query] => write!(f, "{query}"),
+        queries => {
+            write!(f, "(")?;
+            for (index, query) in queries.iter().enumerate() {
+                if index > 0 {
+                    write!(f, " {operator} ")?;
+                }
+                write!(f, "{query}")?;
+            }
+            write!(f, ")")
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::All => write!(f, "*:*"),
+            Self::Text(text) => write!(f, "{}", escape_except(text, " ")),
+            Self::Equals { field, value } => write!(f, "{}:{}", escape(field), quote(value)),
+            Self::Range { field, from, to } => {
+                let bound = |value: &Option<String>| value.as_deref().map_or("*".into(), escape);
+                write!(f, "{}:[{} TO {}]", escape(field), bound(from), bound(to))
+            }
+            Self::Wildcard { field, pattern } => {
+                write!(f, "{}:{}", escape(field), escape_except(pattern, "*?"))
+            }
+            Self::And(queries) => join(f, queries, "AND", Self::All),
+            Self::Or(queries) => join(f, queries, "OR", Self::All.not()),
+            // A purely negative clause matches nothing inside a group, so anchor it to `*:*`.
+            Self::Not(query) => write!(f, "(*:* NOT {query})"),
+            Self::Raw(query) => write!(f, "{query}"),
+        }
+    }
+}
+
+/// The `q` and filter queries of a `package_search` call.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    query: Option<Query>,
+    filters: Vec<Query>,
+}
+
+impl SearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the main query (`q`). Repeated calls are combined with `AND`.
+    pub fn query(mut self, query: Query) -> Self {
+        self.query = Some(match self.query {
+            Some(existing) => existing.and(query),
+            None => query,
+        });
+        self
+    }
+
+    /// Shorthand for `.query(Query::text(text))`.
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.query(Query::text(text))
+    }
+
+    /// Add a filter query. Filters narrow the results without affecting relevance.
+    pub fn filter(mut self, filter: Query) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Filter on the value of a facet item from a previous `SearchResult`.
+    pub fn drill_down(self, facet_field: &str, item: &FacetItem) -> Self {
+        self.filter(item.filter(facet_field))
+    }
+
+    /// The rendered `q` parameter.
+    pub fn q(&self) -> Option<String> {
+        self.query.as_ref().map(Query::to_string)
+    }
+
+    /// All filters rendered as a single `fq` parameter joined with `AND`.
+    pub fn fq(&self) -> Option<String> {
+        (!self.filters.is_empty()).then(|| {
+            self.filters
+                .iter()
+                .map(Query::to_string)
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        })
+    }
+
+    /// Each filter rendered as a separate entry of the `fq_list` parameter.
+    pub fn fq_list(&self) -> Option<Vec<String>> {
+        (!self.filters.is_empty()).then(|| self.filters.iter().map(Query::to_string).collect())
+    }
+}
+
+/// A value of a facet with the number of matching packages.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FacetItem {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    pub count: u64,
+}
+
+impl FacetItem {
+    /// A filter matching the packages counted by this item of `facet_field`.
+    pub fn filter(&self, facet_field: &str) -> Query {
+        Query::field(facet_field, &self.name)
+    }
+}
+
+/// The typed `result` of a `package_search` response.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct SearchResult {
+    pub count: u64,
+    #[serde(default)]
+    pub results: Vec<Package>,
+    /// Facet items keyed by facet field, taken from `search_facets`.
+    #[serde(
+        default,
+        rename = "search_facets",
+        deserialize_with = "deserialize_facets"
+    )]
+    pub facets: HashMap<String, Vec<FacetItem>>,
+}
+
+fn deserialize_facets<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, Vec<FacetItem>>, D::Error> {
+    #[derive(Deserialize)]
+    struct SearchFacet {
+        #[serde(default)]
+        items: Vec<FacetItem>,
+    }
+    let facets = HashMap::<String, SearchFacet>::deserialize(deserializer)?;
+    Ok(facets
+        .into_iter()
+        .map(|(field, facet)| (field, facet.items))
+        .collect())
+}
+
+impl SearchResult {
+    /// Parse a `package_search` response, returning its `error` if the search failed.
+    pub fn from_response(response: serde_json::Value) -> Result<Self, CKANError> {
+        Ok(serde_json::from_value(action_result(response)?)?)
+    }
+
+    /// A filter for the item named `name` of `facet_field`, if it is part of the facets.
+    pub fn drill_down(&self, facet_field: &str, name: &str) -> Option<Query> {
+        self.facets
+            .get(facet_field)?
+            .iter()
+            .find(|item| item.name == name)
+            .map(|item| item.filter(facet_field))
+    }
+}
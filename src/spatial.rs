@@ -0,0 +1,220 @@
+//! ckanext-spatial support: bounding box search and GeoJSON `spatial` extras.
+//!
+//! Enabled with the `spatial` feature, which also adds the `ext_bbox` and `ext_prev_extent`
+//! parameters of `package_search`. They take a [`BBox`]. Geometries are validated before they
+//! are stored as the `spatial` field of a dataset:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::spatial::BBox;
+//!
+//! let netherlands = BBox::new(3.31, 50.75, 7.23, 53.55)?;
+//! let results = ckan
+//!     .package_search()
+//!     .ext_bbox(netherlands)
+//!     .call()
+//!     .await?;
+//!
+//! let outline = ckanaction::spatial::parse(r#"{"type": "Point", "coordinates": [4.9, 52.37]}"#)?;
+//! ckan.package_set_spatial()
+//!     .id("amsterdam-trees")
+//!     .geometry(outline)
+//!     .call()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError, action_result};
+use bon::bon;
+use geojson::{Geometry, Value};
+use serde_json::json;
+use std::fmt;
+
+/// A WGS84 bounding box, as used by the `ext_bbox` search parameter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl BBox {
+    /// A bounding box from its west, south, east and north edges in degrees.
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Result<Self, CKANError> {
+        validate_position(&[min_x, min_y])?;
+        validate_position(&[max_x, max_y])?;
+        if min_x > max_x || min_y > max_y {
+            return Err(CKANError::GeoJsonError(format!(
+                "the minimum of bounding box {min_x},{min_y},{max_x},{max_y} exceeds its maximum"
+            )));
+        }
+        Ok(Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        })
+    }
+
+    /// The box as a GeoJSON polygon, the way ckanext-spatial stores bounding boxes.
+    pub fn to_geometry(&self) -> Geometry {
+        let Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        } = *self;
+        Geometry::new(Value::Polygon(vec![vec![
+            vec![min_x, min_y],
+            vec![max_x, min_y],
+            vec![max_x, max_y],
+            vec![min_x, max_y],
+            vec![min_x, min_y],
+        ]]))
+    }
+}
+
+impl fmt::Display for BBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
+
+impl From<BBox> for String {
+    fn from(bbox: BBox) -> Self {
+        bbox.to_string()
+    }
+}
+
+impl serde::Serialize for BBox {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl From<BBox> for Geometry {
+    fn from(bbox: BBox) -> Self {
+        bbox.to_geometry()
+    }
+}
+
+fn validate_position(position: &[f64]) -> Result<(), CKANError> {
+    let error = |message: &str| {
+        Err(CKANError::GeoJsonError(format!(
+            "position {position:?} {message}"
+        )))
+    };
+    match position {
+        [x, y] | [x, y, _] => {
+            if !position.iter().all(|value| value.is_finite()) {
+                error("is not finite")
+            } else if !(-180.0..=180.0).contains(x) {
+                error("has a longitude outside of -180 to 180")
+            } else if !(-90.0..=90.0).contains(y) {
+                error("has a latitude outside of -90 to 90")
+            } else {
+                Ok(())
+            }
+        }
+        _ => error("does not have 2 or 3 coordinates"),
+    }
+}
+
+fn validate_line(line: &[Vec<f64>]) -> Result<(), CKANError> {
+    if line.len() < 2 {
+        return Err(CKANError::GeoJsonError(
+            "a line string needs at least 2 positions".to_string(),
+        ));
+    }
+    line.iter()
+        .try_for_each(|position| validate_position(position))
+}
+
+fn validate_polygon(polygon: &[Vec<Vec<f64>>]) -> Result<(), CKANError> {
+    if polygon.is_empty() {
+        return Err(CKANError::GeoJsonError(
+            "a polygon needs at least 1 ring".to_string(),
+        ));
+    }
+    for ring in polygon {
+        if ring.len() < 4 || ring.first() != ring.last() {
+            return Err(CKANError::GeoJsonError(
+                "a polygon ring needs at least 4 positions and must end where it starts"
+                    .to_string(),
+            ));
+        }
+        validate_line(ring)?;
+    }
+    Ok(())
+}
+
+/// Check that `geometry` is valid GeoJSON in WGS84: positions are within range, line strings
+/// have 2 positions or more and polygon rings are closed.
+pub fn validate(geometry: &Geometry) -> Result<(), CKANError> {
+    match &geometry.value {
+        Value::Point(position) => validate_position(position),
+        Value::MultiPoint(positions) => positions
+            .iter()
+            .try_for_each(|position| validate_position(position)),
+        Value::LineString(line) => validate_line(line),
+        Value::MultiLineString(lines) => lines.iter().try_for_each(|line| validate_line(line)),
+        Value::Polygon(polygon) => validate_polygon(polygon),
+        Value::MultiPolygon(polygons) => polygons
+            .iter()
+            .try_for_each(|polygon| validate_polygon(polygon)),
+        Value::GeometryCollection(geometries) => geometries.iter().try_for_each(validate),
+    }
+}
+
+/// Parse and [`validate`] a GeoJSON geometry.
+pub fn parse(geojson: &str) -> Result<Geometry, CKANError> {
+    let geometry: Geometry = geojson
+        .parse()
+        .map_err(|error: geojson::Error| CKANError::GeoJsonError(error.to_string()))?;
+    validate(&geometry)?;
+    Ok(geometry)
+}
+
+/// The value of the `spatial` field for `geometry`, after validating it.
+pub fn spatial_value(geometry: &Geometry) -> Result<String, CKANError> {
+    validate(geometry)?;
+    Ok(serde_json::to_string(geometry)?)
+}
+
+#[bon]
+impl CKAN {
+    /// Set the `spatial` field of the dataset `id` to `geometry`.
+    ///
+    /// The field is patched directly if the dataset has it as a field (e.g. with a scheming
+    /// schema), and otherwise as the `spatial` extra, keeping the other extras.
+    #[builder(on(String, into))]
+    pub async fn package_set_spatial(
+        &self,
+        id: String,
+        #[builder(into)] geometry: Geometry,
+    ) -> Result<serde_json::Value, CKANError> {
+        let spatial = spatial_value(&geometry)?;
+        let package = action_result(self.package_show().id(&id).call().await?)?;
+        let body = match package.get("spatial") {
+            Some(_) => json!({ "id": id, "spatial": spatial }),
+            None => {
+                let mut extras: Vec<serde_json::Value> = package["extras"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|extra| extra["key"] != "spatial")
+                    .cloned()
+                    .collect();
+                extras.push(json!({ "key": "spatial", "value": spatial }));
+                json!({ "id": id, "extras": extras })
+            }
+        };
+        self.call_action("package_patch", body).await
+    }
+}
@@ -0,0 +1,350 @@
+//! Build `datastore_search_sql` queries without interpolating strings.
+//!
+//! Table names (resource ids) and columns are always quoted identifiers and values are always
+//! escaped literals: a Rust string becomes a SQL string, never SQL.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::sql::{Order, Query, avg, col};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Station {
+//!     station: String,
+//!     average: f64,
+//! }
+//!
+//! let query = Query::from("0b7f5d5e-8c1a-4c1e-9a44-1c3f2b0e6f7a")
+//!     .select([col("station"), avg(col("value")).alias("average")])
+//!     .filter(col("year").ge(2000).and(col("station").ne("test")))
+//!     .group_by([col("station")])
+//!     .order_by(col("average"), Order::Desc)
+//!     .limit(10);
+//! let stations: Vec<Station> = ckan.datastore_query(&query).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError};
+use serde_json::json;
+use std::fmt;
+
+/// Quote `name` as an identifier, e.g. a column or a resource id.
+pub fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('\0', "").replace('"', "\"\""))
+}
+
+/// Quote `value` as a string literal.
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\0', "").replace('\'', "''"))
+}
+
+/// A SQL expression: a column, a literal value or a combination of them.
+///
+/// Strings, numbers and booleans convert into literals, so they can be passed wherever an
+/// expression is expected. Use [`col`] for columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr(String);
+
+/// A column of the queried table.
+pub fn col(name: &str) -> Expr {
+    Expr(quote_ident(name))
+}
+
+/// The SQL `NULL`.
+pub fn null() -> Expr {
+    Expr("NULL".to_string())
+}
+
+/// `COUNT(*)`
+pub fn count_all() -> Expr {
+    Expr("COUNT(*)".to_string())
+}
+
+fn function(name: &str, expr: Expr) -> Expr {
+    Expr(format!("{name}({})", expr.0))
+}
+
+pub fn count(expr: Expr) -> Expr {
+    function("COUNT", expr)
+}
+
+pub fn sum(expr: Expr) -> Expr {
+    function("SUM", expr)
+}
+
+pub fn avg(expr: Expr) -> Expr {
+    function("AVG", expr)
+}
+
+pub fn min(expr: Expr) -> Expr {
+    function("MIN", expr)
+}
+
+pub fn max(expr: Expr) -> Expr {
+    function("MAX", expr)
+}
+
+impl Expr {
+    fn binary(self, operator: &str, other: impl Into<Expr>) -> Expr {
+        Expr(format!("{} {operator} {}", self.0, other.into().0))
+    }
+
+    /// `self = other`, or `self IS NULL` if `other` is `NULL`.
+    pub fn eq(self, other: impl Into<Expr>) -> Expr {
+        match other.into() {
+            other if other == null() => self.is_null(),
+            other => self.binary("=", other),
+        }
+    }
+
+    /// `self <> other`, or `self IS NOT NULL` if `other` is `NULL`.
+    pub fn ne(self, other: impl Into<Expr>) -> Expr {
+        match other.into() {
+            other if other == null() => self.is_not_null(),
+            other => self.binary("<>", other),
+        }
+    }
+
+    pub fn gt(self, other: impl Into<Expr>) -> Expr {
+        self.binary(">", other)
+    }
+
+    pub fn ge(self, other: impl Into<Expr>) -> Expr {
+        self.binary(">=", other)
+    }
+
+    pub fn lt(self, other: impl Into<Expr>) -> Expr {
+        self.binary("<", other)
+    }
+
+    pub fn le(self, other: impl Into<Expr>) -> Expr {
+        self.binary("<=", other)
+    }
+
+    pub fn like(self, pattern: impl Into<Expr>) -> Expr {
+        self.binary("LIKE", pattern)
+    }
+
+    pub fn ilike(self, pattern: impl Into<Expr>) -> Expr {
+        self.binary("ILIKE", pattern)
+    }
+
+    /// `self IN (values...)`, which is false for no values.
+    pub fn is_in<E: Into<Expr>>(self, values: impl IntoIterator<Item = E>) -> Expr {
+        let values: Vec<String> = values.into_iter().map(|value| value.into().0).collect();
+        if values.is_empty() {
+            Expr("FALSE".to_string())
+        } else {
+            Expr(format!("{} IN ({})", self.0, values.join(", ")))
+        }
+    }
+
+    pub fn is_null(self) -> Expr {
+        Expr(format!("{} IS NULL", self.0))
+    }
+
+    pub fn is_not_null(self) -> Expr {
+        Expr(format!("{} IS NOT NULL", self.0))
+    }
+
+    pub fn and(self, other: Expr) -> Expr {
+        Expr(format!("({}) AND ({})", self.0, other.0))
+    }
+
+    pub fn or(self, other: Expr) -> Expr {
+        Expr(format!("({}) OR ({})", self.0, other.0))
+    }
+
+    /// Name the expression in the selected columns.
+    pub fn alias(self, name: &str) -> Expr {
+        Expr(format!("{} AS {}", self.0, quote_ident(name)))
+    }
+}
+
+impl std::ops::Not for Expr {
+    type Output = Expr;
+
+    fn not(self) -> Expr {
+        Expr(format!("NOT ({})", self.0))
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Expr {
+    fn from(value: &str) -> Self {
+        Expr(quote_literal(value))
+    }
+}
+
+impl From<String> for Expr {
+    fn from(value: String) -> Self {
+        Expr::from(value.as_str())
+    }
+}
+
+impl From<bool> for Expr {
+    fn from(value: bool) -> Self {
+        Expr(value.to_string().to_uppercase())
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        if value.is_finite() {
+            Expr(format!("{value:?}"))
+        } else {
+            Expr(format!("{}::float8", quote_literal(&value.to_string())))
+        }
+    }
+}
+
+macro_rules! integer_literal {
+    ($($ty: ty),*) => {
+        $(impl From<$ty> for Expr {
+            fn from(value: $ty) -> Self {
+                Expr(value.to_string())
+            }
+        })*
+    };
+}
+
+integer_literal!(i32, i64, u32, u64, usize);
+
+impl<T: Into<Expr>> From<Option<T>> for Expr {
+    fn from(value: Option<T>) -> Self {
+        value.map_or_else(null, Into::into)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// A `SELECT` from the DataStore table of one resource.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    table: String,
+    columns: Vec<Expr>,
+    filters: Vec<Expr>,
+    group_by: Vec<Expr>,
+    order_by: Vec<(Expr, Order)>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+impl Query {
+    /// A query of the table of `resource_id`, selecting all columns until [`Query::select`] is
+    /// called.
+    pub fn from(resource_id: impl AsRef<str>) -> Self {
+        Self {
+            table: quote_ident(resource_id.as_ref()),
+            columns: vec![],
+            filters: vec![],
+            group_by: vec![],
+            order_by: vec![],
+            limit: None,
+            offset: None,
+        }
+    }
+
+    pub fn select(mut self, columns: impl IntoIterator<Item = Expr>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    /// Add a `WHERE` condition. Multiple conditions must all hold.
+    pub fn filter(mut self, condition: Expr) -> Self {
+        self.filters.push(condition);
+        self
+    }
+
+    pub fn group_by(mut self, columns: impl IntoIterator<Item = Expr>) -> Self {
+        self.group_by.extend(columns);
+        self
+    }
+
+    pub fn order_by(mut self, expr: Expr, order: Order) -> Self {
+        self.order_by.push((expr, order));
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn to_sql(&self) -> String {
+        let join = |exprs: &[Expr]| {
+            exprs
+                .iter()
+                .map(|expr| expr.0.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut sql = if self.columns.is_empty() {
+            format!("SELECT * FROM {}", self.table)
+        } else {
+            format!("SELECT {} FROM {}", join(&self.columns), self.table)
+        };
+        if !self.filters.is_empty() {
+            let filters: Vec<String> = self
+                .filters
+                .iter()
+                .map(|filter| format!("({})", filter.0))
+                .collect();
+            sql += &format!(" WHERE {}", filters.join(" AND "));
+        }
+        if !self.group_by.is_empty() {
+            sql += &format!(" GROUP BY {}", join(&self.group_by));
+        }
+        if !self.order_by.is_empty() {
+            let order_by: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(expr, order)| match order {
+                    Order::Asc => format!("{} ASC", expr.0),
+                    Order::Desc => format!("{} DESC", expr.0),
+                })
+                .collect();
+            sql += &format!(" ORDER BY {}", order_by.join(", "));
+        }
+        if let Some(limit) = self.limit {
+            sql += &format!(" LIMIT {limit}");
+        }
+        if let Some(offset) = self.offset {
+            sql += &format!(" OFFSET {offset}");
+        }
+        sql
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_sql())
+    }
+}
+
+impl CKAN {
+    /// Run `query` with `datastore_search_sql` and deserialize the returned rows into `T`.
+    pub async fn datastore_query<T: serde::de::DeserializeOwned>(
+        &self,
+        query: &Query,
+    ) -> Result<Vec<T>, CKANError> {
+        let mut result = self
+            .call_action("datastore_search_sql", json!({ "sql": query.to_sql() }))
+            .await?;
+        Ok(serde_json::from_value(result["records"].take())?)
+    }
+}
@@ -0,0 +1,204 @@
+//! Rotate API tokens and find the ones to clean up.
+//!
+//! CKAN API tokens are JWTs, so [`Claims::decode`] reads their id and lifetime without asking the
+//! server. [`CKAN::rotate_token`] replaces the token of a client while it is in use, and
+//! [`CKAN::revoke_token_after`] revokes the previous one once its other users switched too:
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use std::time::Duration;
+//!
+//! let day = Duration::from_secs(24 * 60 * 60);
+//! let rotation = ckan
+//!     .rotate_token()
+//!     .user("harvester")
+//!     .name("nightly harvest")
+//!     .expires_in(30 * day)
+//!     .call()
+//!     .await?;
+//! println!("now using token {}", rotation.claims.jti);
+//! if let Some(previous) = rotation.previous {
+//!     ckan.revoke_token_after()
+//!         .jti(previous.jti)
+//!         .grace_period(Duration::from_secs(60))
+//!         .call()
+//!         .await?;
+//! }
+//!
+//! for token in ckan.stale_tokens().user_id("harvester").call().await? {
+//!     println!("{} expires soon or was never used", token.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError, action_result, credentials};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use bon::bon;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+/// The claims CKAN puts in an API token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    /// The id of the token, as listed by `api_token_list`.
+    pub jti: String,
+    /// Unix time the token was issued at.
+    pub iat: Option<i64>,
+    /// Unix time the token expires at, if it expires.
+    pub exp: Option<i64>,
+}
+
+impl Claims {
+    /// Read the claims of `token` without verifying its signature.
+    pub fn decode(token: &str) -> Result<Self, CKANError> {
+        let invalid = |message: String| CKANError::InvalidToken(message);
+        let [_, payload, _] = token.split('.').collect::<Vec<_>>()[..] else {
+            return Err(invalid("a JWT has 3 parts separated by dots".to_string()));
+        };
+        let payload = URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|error| invalid(error.to_string()))?;
+        serde_json::from_slice(&payload).map_err(|error| invalid(error.to_string()))
+    }
+
+    /// Whether the token expires within `within` from now. Tokens without `exp` never do.
+    pub fn expires_within(&self, within: Duration) -> bool {
+        expires_within(self.exp, within)
+    }
+}
+
+fn expires_within(exp: Option<i64>, within: Duration) -> bool {
+    let deadline = chrono::Utc::now().timestamp() + within.as_secs() as i64;
+    exp.is_some_and(|exp| exp <= deadline)
+}
+
+/// An API token as returned by `api_token_list`. The token itself is only returned on creation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiToken {
+    /// The `jti` claim of the token.
+    pub id: String,
+    pub name: String,
+    pub user_id: String,
+    pub created_at: Option<String>,
+    pub last_access: Option<String>,
+    #[serde(default)]
+    pub plugin_extras: Option<Value>,
+}
+
+impl ApiToken {
+    /// Unix time the token expires at, as stored by the `expire_api_token` plugin.
+    pub fn expires(&self) -> Option<i64> {
+        let exp = self.plugin_extras.as_ref()?["expire_api_token"]["exp"].as_str()?;
+        let exp = exp.replacen(' ', "T", 1);
+        match chrono::DateTime::parse_from_rfc3339(&exp) {
+            Ok(exp) => Some(exp.timestamp()),
+            Err(_) => chrono::NaiveDateTime::parse_from_str(&exp, "%Y-%m-%dT%H:%M:%S%.f")
+                .ok()
+                .map(|exp| exp.and_utc().timestamp()),
+        }
+    }
+
+    /// Whether the token expires within `within` from now.
+    pub fn expires_within(&self, within: Duration) -> bool {
+        expires_within(self.expires(), within)
+    }
+
+    pub fn is_unused(&self) -> bool {
+        self.last_access.is_none()
+    }
+}
+
+/// The outcome of `rotate_token`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rotation {
+    /// The new token, which the client now sends.
+    pub token: String,
+    pub claims: Claims,
+    /// The claims of the token the client sent before, which is still valid. `None` if the client
+    /// had no token or a legacy API key, which `api_token_revoke` can not revoke.
+    pub previous: Option<Claims>,
+}
+
+#[bon]
+impl CKAN {
+    /// Create a token for `user` and switch this client to it. The previous token stays valid,
+    /// so that other users of it can switch too; revoke it with [`CKAN::revoke_token_after`].
+    ///
+    /// `expires_in` needs the `expire_api_token` plugin and is rounded down to seconds. Fails with
+    /// `CKANError::Unsupported` if the token comes from a provider other than a fixed token, e.g.
+    /// a `FileToken`, which would stop being read: rotate the token at its source instead.
+    #[builder(on(String, into))]
+    pub async fn rotate_token(
+        &self,
+        user: String,
+        name: String,
+        expires_in: Option<Duration>,
+    ) -> Result<Rotation, CKANError> {
+        if let Some(provider) = self.credentials()
+            && !credentials::is_static(provider.as_ref())
+        {
+            return Err(CKANError::Unsupported(format!(
+                "can not rotate a token that comes from {provider:?}"
+            )));
+        }
+        let previous = self
+            .token()
+            .await?
+            .and_then(|token| Claims::decode(&token).ok());
+        let expires_in =
+            expires_in.map(|expires_in| u32::try_from(expires_in.as_secs()).unwrap_or(u32::MAX));
+        let created = action_result(
+            self.api_token_create()
+                .user(user)
+                .name(name)
+                .maybe_expires_in(expires_in)
+                .maybe_unit(expires_in.map(|_| 1))
+                .call()
+                .await?,
+        )?;
+        let token = created["token"].as_str().ok_or_else(|| {
+            CKANError::InvalidToken("api_token_create did not return a token".to_string())
+        })?;
+        let claims = Claims::decode(token)?;
+        self.set_token(Some(token.to_string()));
+        Ok(Rotation {
+            token: token.to_string(),
+            claims,
+            previous,
+        })
+    }
+
+    /// Revoke the token with the id `jti` after `grace_period` (none by default), e.g. the
+    /// `previous` token of a [`Rotation`].
+    #[builder(on(String, into))]
+    pub async fn revoke_token_after(
+        &self,
+        jti: String,
+        grace_period: Option<Duration>,
+    ) -> Result<(), CKANError> {
+        if let Some(grace_period) = grace_period {
+            tokio::time::sleep(grace_period).await;
+        }
+        action_result(self.api_token_revoke().jti(jti).call().await?)?;
+        Ok(())
+    }
+
+    /// The tokens of `user_id` that expire within `expiring_within` (7 days by default) or were
+    /// never used.
+    #[builder(on(String, into))]
+    pub async fn stale_tokens(
+        &self,
+        user_id: String,
+        expiring_within: Option<Duration>,
+    ) -> Result<Vec<ApiToken>, CKANError> {
+        let expiring_within = expiring_within.unwrap_or(Duration::from_secs(7 * 24 * 60 * 60));
+        let tokens = action_result(self.api_token_list().user_id(user_id).call().await?)?;
+        let tokens: Vec<ApiToken> = serde_json::from_value(tokens)?;
+        Ok(tokens
+            .into_iter()
+            .filter(|token| token.is_unused() || token.expires_within(expiring_within))
+            .collect())
+    }
+}
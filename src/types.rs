@@ -0,0 +1,196 @@
+//! Typed models of the objects returned by CKAN actions.
+//!
+//! Only the core fields are typed. Anything else a CKAN instance returns (e.g. fields added by
+//! ckanext-scheming) is kept in `custom_fields`.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+macro_rules! id_type {
+    ($(#[$meta: meta])* $name: ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_string())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl From<&$name> for String {
+            fn from(id: &$name) -> Self {
+                id.0.clone()
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+id_type! {
+    /// Id of a dataset. Can be passed to any builder parameter that takes a package id.
+    PackageId
+}
+
+id_type! {
+    /// Id of a resource. Can be passed to any builder parameter that takes a resource id.
+    ResourceId
+}
+
+id_type! {
+    /// Id of an organization or group. Can be passed to any builder parameter that takes an
+    /// organization or group id.
+    OrgId
+}
+
+id_type! {
+    /// Id of a user. Can be passed to any builder parameter that takes a user id.
+    UserId
+}
+
+/// A dataset as returned by `package_show` or in `package_search` results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Package {
+    pub id: PackageId,
+    pub name: String,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub private: bool,
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    pub maintainer: Option<String>,
+    pub maintainer_email: Option<String>,
+    pub license_id: Option<String>,
+    pub notes: Option<String>,
+    pub url: Option<String>,
+    pub version: Option<String>,
+    pub state: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub owner_org: Option<OrgId>,
+    pub metadata_created: Option<String>,
+    pub metadata_modified: Option<String>,
+    pub organization: Option<Group>,
+    #[serde(default)]
+    pub resources: Vec<Resource>,
+    #[serde(default)]
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub groups: Vec<Group>,
+    #[serde(default)]
+    pub extras: Vec<Extra>,
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Resource {
+    pub id: ResourceId,
+    pub package_id: Option<PackageId>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub format: Option<String>,
+    pub mimetype: Option<String>,
+    pub size: Option<i64>,
+    pub url_type: Option<String>,
+    pub datastore_active: Option<bool>,
+    pub position: Option<i32>,
+    pub state: Option<String>,
+    pub created: Option<String>,
+    pub last_modified: Option<String>,
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Tag {
+    pub id: Option<String>,
+    pub name: String,
+    pub display_name: Option<String>,
+    pub vocabulary_id: Option<String>,
+    pub state: Option<String>,
+}
+
+/// A group or an organization.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Group {
+    pub id: OrgId,
+    pub name: String,
+    pub title: Option<String>,
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    #[serde(rename = "type")]
+    pub _type: Option<String>,
+    pub is_organization: Option<bool>,
+    pub state: Option<String>,
+    pub approval_status: Option<String>,
+    pub created: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Extra {
+    pub key: String,
+    pub value: serde_json::Value,
+}
+
+/// A user as returned by `user_show` or `user_list`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub id: UserId,
+    pub name: String,
+    pub fullname: Option<String>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub about: Option<String>,
+    pub image_url: Option<String>,
+    #[serde(default)]
+    pub sysadmin: bool,
+    pub state: Option<String>,
+    pub created: Option<String>,
+    #[serde(flatten)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+}
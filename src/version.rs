@@ -0,0 +1,163 @@
+//! CKAN version detection and version-gated actions.
+//!
+//! The version is read from `status_show` the first time it is needed, along with the
+//! [capabilities](crate::capabilities), and cached on the `CKAN`.
+//! Actions that only exist in newer CKAN versions (see [`minimum_version`]) fail with
+//! `CKANError::Unsupported` on older servers instead of sending a request that can only fail, and
+//! so do actions that moved into a plugin the server does not run (see [`required_plugin`]). In
+//! dry-run mode, mutating actions are only checked against a version that is already known.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::version::Version;
+//!
+//! if ckan.server_version().await? >= Version::new(2, 10, 0) {
+//!     println!("activities are provided by the activity plugin");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Build the `CKAN` with `.version(..)` to skip the detection, e.g. when `status_show` is not
+//! reachable.
+
+use crate::{CKAN, CKANError};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parse a `ckan_version` such as `2.10.4`, `2.11` or `2.12.0a`. Pre-release suffixes are
+    /// ignored.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.').map(|part| {
+            let digits = part
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(part.len());
+            part[..digits].parse::<u32>().ok()
+        });
+        Some(Self::new(
+            parts.next()??,
+            parts.next()??,
+            parts.next().flatten().unwrap_or(0),
+        ))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The first CKAN version providing `action`, for actions added after CKAN 2.9.
+pub fn minimum_version(action: &str) -> Option<Version> {
+    match action {
+        "file_create"
+        | "file_register"
+        | "file_delete"
+        | "file_show"
+        | "file_rename"
+        | "file_pin"
+        | "file_unpin"
+        | "file_ownership_transfer" => Some(Version::new(2, 12, 0)),
+        _ => None,
+    }
+}
+
+/// The plugin that provides `action` on servers running `version`, for core actions that moved
+/// into a plugin. Activities are part of the core up to CKAN 2.9 and need the `activity` plugin
+/// since CKAN 2.10.
+pub fn required_plugin(action: &str, version: Version) -> Option<&'static str> {
+    moved_to_plugin(action)
+        .filter(|(_, since)| version >= *since)
+        .map(|(plugin, _)| plugin)
+}
+
+fn moved_to_plugin(action: &str) -> Option<(&'static str, Version)> {
+    match action {
+        "activity_create"
+        | "activity_show"
+        | "activity_data_show"
+        | "activity_diff"
+        | "package_activity_list"
+        | "group_activity_list"
+        | "organization_activity_list"
+        | "user_activity_list"
+        | "recently_changed_packages_activity_list"
+        | "dashboard_activity_list"
+        | "dashboard_new_activities_count"
+        | "dashboard_mark_activities_old"
+        | "send_email_notifications" => Some(("activity", Version::new(2, 10, 0))),
+        _ => None,
+    }
+}
+
+fn too_old(action: &str, version: Version) -> Result<(), CKANError> {
+    match minimum_version(action) {
+        Some(required) if version < required => Err(CKANError::Unsupported(format!(
+            "{action} requires CKAN {required} or later, the server runs CKAN {version}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl CKAN {
+    /// The version of CKAN the server runs, detected with `status_show` on the first call. A
+    /// failed detection is not kept, so the next call asks again.
+    pub async fn server_version(&self) -> Result<Version, CKANError> {
+        let version = match self.version.get() {
+            Some(version) => *version,
+            None => {
+                let detected = self.capabilities().await?.version;
+                *self.version.get_or_init(|| detected)
+            }
+        };
+        version.ok_or_else(|| {
+            CKANError::Unsupported("the server does not report its CKAN version".to_string())
+        })
+    }
+
+    /// Fail with `CKANError::Unsupported` if the server is too old for `action`, or does not run
+    /// the plugin that provides it. When the version can not be detected the action is sent
+    /// anyway and the server decides.
+    pub(crate) async fn check_version(&self, action: &str) -> Result<(), CKANError> {
+        if minimum_version(action).is_none() && moved_to_plugin(action).is_none() {
+            return Ok(());
+        }
+        let Ok(version) = self.server_version().await else {
+            return Ok(());
+        };
+        too_old(action, version)?;
+        match required_plugin(action, version) {
+            Some(plugin) if !self.capabilities().await?.has_extension(plugin) => {
+                Err(CKANError::Unsupported(format!(
+                    "{action} needs the {plugin} plugin on CKAN {version}, it is not enabled on the server"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Like `check_version`, but only against a version that is already known, so that no
+    /// request is sent, e.g. in dry-run mode.
+    pub(crate) fn check_known_version(&self, action: &str) -> Result<(), CKANError> {
+        match self.version.get() {
+            Some(Some(version)) => too_old(action, *version),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,270 @@
+//! Typed resource view configs and syncing the views of a resource.
+//!
+//! Every built-in view plugin has a config struct implementing [`ViewConfig`]. Implement it for
+//! the options of other view plugins.
+//!
+//! ```no_run
+//! # async fn example(ckan: ckanaction::CKAN) -> Result<(), ckanaction::CKANError> {
+//! use ckanaction::views::{DatatablesView, ImageView, View};
+//!
+//! let views = vec![
+//!     View::new("Table", &DatatablesView {
+//!         responsive: Some(true),
+//!         ..Default::default()
+//!     })?,
+//!     View::new("Chart", &ImageView {
+//!         image_url: "https://example.org/chart.png".to_string(),
+//!     })?,
+//! ];
+//! let sync = ckan
+//!     .sync_resource_views()
+//!     .resource_id("0b7f5d5e-8c1a-4c1e-9a44-1c3f2b0e6f7a")
+//!     .views(views)
+//!     .call()
+//!     .await?;
+//! println!("{} created, {} deleted", sync.created.len(), sync.deleted.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{CKAN, CKANError, action_result, ensure::is_subset, params::ViewType};
+use bon::bon;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The options of a view plugin, sent as the `config` of `resource_view_create` and
+/// `resource_view_update`.
+pub trait ViewConfig: Serialize {
+    fn view_type(&self) -> ViewType;
+
+    /// The options as a JSON object.
+    fn to_config(&self) -> Result<Value, CKANError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    /// All the options of the view type, including unset ones. Defaults to the keys of
+    /// `to_config`.
+    fn keys(&self) -> Result<Vec<String>, CKANError> {
+        Ok(match self.to_config()? {
+            Value::Object(config) => config.keys().cloned().collect(),
+            _ => vec![],
+        })
+    }
+}
+
+macro_rules! view_config {
+    ($(#[$meta: meta])* $name: ident => $view_type: ident { $($(#[$field_meta: meta])* $field: ident: $ty: ty),* $(,)? }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+        pub struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl ViewConfig for $name {
+            fn view_type(&self) -> ViewType {
+                ViewType::$view_type
+            }
+
+            fn keys(&self) -> Result<Vec<String>, CKANError> {
+                Ok(vec![$(stringify!($field).to_string()),*])
+            }
+        }
+    };
+}
+
+view_config! {
+    /// `image_view`: shows the image at `image_url`.
+    ImageView => ImageView { image_url: String }
+}
+
+view_config! {
+    /// `webpage_view`: embeds the page at `page_url`.
+    WebpageView => WebpageView { page_url: String }
+}
+
+view_config! {
+    /// `datatables_view`: a searchable table of the DataStore rows of the resource.
+    DatatablesView => DatatablesView {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        responsive: Option<bool>,
+        /// Fields to show, all by default.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        show_fields: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filterable: Option<bool>,
+    }
+}
+
+view_config! {
+    /// `recline_view`: the data explorer of CKAN 2.10 and earlier.
+    ReclineView => ReclineView {}
+}
+
+view_config! {
+    /// `text_view`: shows text, JSON and XML files.
+    TextView => TextView {}
+}
+
+view_config! {
+    /// `pdf_view` of ckanext-pdfview.
+    PdfView => PdfView {}
+}
+
+view_config! {
+    /// `geojson_view` of ckanext-geoview.
+    GeoJsonView => GeoJsonView {}
+}
+
+view_config! {
+    /// `video_view`: plays the video at `video_url`.
+    VideoView => VideoView {
+        video_url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        poster_url: Option<String>,
+    }
+}
+
+view_config! {
+    /// `audio_view`: plays the audio at `audio_url`.
+    AudioView => AudioView { audio_url: String }
+}
+
+/// The id of a view returned by CKAN. Fails with `CKANError::SerdeJsonError` if it has none.
+fn view_id(view: &Value) -> Result<String, CKANError> {
+    #[derive(Deserialize)]
+    struct View {
+        id: String,
+    }
+    Ok(View::deserialize(view)?.id)
+}
+
+/// A view that a resource should have. Views are matched to existing ones by type and title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct View {
+    pub title: String,
+    pub description: Option<String>,
+    pub view_type: ViewType,
+    pub config: Value,
+    /// The options of the view type. Those missing from `config` are compared as unset.
+    pub keys: Vec<String>,
+}
+
+impl View {
+    pub fn new(title: impl Into<String>, config: &impl ViewConfig) -> Result<Self, CKANError> {
+        Ok(Self {
+            title: title.into(),
+            description: None,
+            view_type: config.view_type(),
+            config: config.to_config()?,
+            keys: config.keys()?,
+        })
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Whether the options of `current`, as returned by CKAN, are those of this view.
+    fn config_matches(&self, current: &Value) -> bool {
+        self.keys.iter().all(|key| match self.config.get(key) {
+            None | Some(Value::Null) => current[key].is_null() || current[key] == "",
+            Some(desired) => is_subset(desired, &current[key]),
+        })
+    }
+}
+
+/// Ids of the views touched by `sync_resource_views`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ViewSync {
+    pub created: Vec<String>,
+    pub updated: Vec<String>,
+    pub unchanged: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
+#[bon]
+impl CKAN {
+    /// Make the views of `resource_id` match `views`, in that order.
+    ///
+    /// Matching views are updated if their description or config differ, missing ones are
+    /// created, and other views are deleted unless `keep_others` is set, in which case they are
+    /// ordered after `views`.
+    #[builder(on(String, into))]
+    pub async fn sync_resource_views(
+        &self,
+        resource_id: String,
+        views: Vec<View>,
+        keep_others: Option<bool>,
+    ) -> Result<ViewSync, CKANError> {
+        let existing = action_result(self.resource_view_list().id(&resource_id).call().await?)?;
+        let mut existing: Vec<Value> = serde_json::from_value(existing)?;
+        let mut current_order = existing
+            .iter()
+            .map(view_id)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut sync = ViewSync::default();
+        let mut order = vec![];
+        for view in views {
+            let position = existing.iter().position(|current| {
+                current["view_type"] == view.view_type.as_str() && current["title"] == view.title
+            });
+            let Some(current) = position.map(|position| existing.remove(position)) else {
+                let created = action_result(
+                    self.resource_view_create()
+                        .resource_id(&resource_id)
+                        .title(view.title)
+                        .maybe_description(view.description)
+                        .view_type(view.view_type)
+                        .config(view.config)
+                        .call()
+                        .await?,
+                )?;
+                let id = view_id(&created)?;
+                sync.created.push(id.clone());
+                current_order.push(id.clone());
+                order.push(id);
+                continue;
+            };
+            let id = view_id(&current)?;
+            let description = Value::from(view.description.clone().unwrap_or_default());
+            if view.config_matches(&current) && is_subset(&description, &current["description"]) {
+                sync.unchanged.push(id.clone());
+            } else {
+                action_result(
+                    self.resource_view_update()
+                        .id(&id)
+                        .resource_id(&resource_id)
+                        .title(view.title)
+                        .maybe_description(view.description)
+                        .view_type(view.view_type)
+                        .config(view.config)
+                        .call()
+                        .await?,
+                )?;
+                sync.updated.push(id.clone());
+            }
+            order.push(id);
+        }
+        for other in existing {
+            let id = view_id(&other)?;
+            if keep_others.unwrap_or(false) {
+                order.push(id);
+            } else {
+                action_result(self.resource_view_delete().id(&id).call().await?)?;
+                current_order.retain(|current| *current != id);
+                sync.deleted.push(id);
+            }
+        }
+        if order != current_order {
+            action_result(
+                self.resource_view_reorder()
+                    .id(&resource_id)
+                    .order(order)
+                    .call()
+                    .await?,
+            )?;
+        }
+        Ok(sync)
+    }
+}
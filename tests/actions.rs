@@ -0,0 +1,56 @@
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    actions::{PackageSearch, PackageShow, StatusShow},
+    params::PackageSort,
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn send_typed_requests_and_read_typed_results() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let url = common::serve(move |action, body| {
+        sink.lock()
+            .unwrap()
+            .push((action.to_string(), body.clone()));
+        match action {
+            "status_show" => json!({
+                "success": true,
+                "result": { "ckan_version": "2.11.1", "extensions": ["scheming_datasets"] },
+            }),
+            "package_search" => json!({
+                "success": true,
+                "result": { "count": 1, "results": [{ "id": "d1", "name": "trees", "tags": [] }] },
+            }),
+            _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+
+    let status = ckan.send(&StatusShow).await.unwrap();
+    assert_eq!(status.ckan_version, "2.11.1");
+    assert_eq!(status.extensions.unwrap(), ["scheming_datasets"]);
+    assert_eq!(status.site_title, None);
+
+    let search = PackageSearch::builder()
+        .q("trees")
+        .rows(10)
+        .sort(PackageSort::NameAsc)
+        .build();
+    let found = ckan.send(&search).await.unwrap();
+    assert_eq!(found.count, 1);
+    assert_eq!(found.results[0].name, "trees");
+    assert_eq!(
+        sent.lock().unwrap()[1],
+        (
+            "package_search".to_string(),
+            json!({ "q": "trees", "rows": 10, "sort": "name asc" })
+        )
+    );
+
+    let missing = ckan.send(&PackageShow::builder().id("gone").build()).await;
+    assert!(matches!(missing, Err(CKANError::ActionError(_))));
+}
@@ -0,0 +1,54 @@
+use ckanaction::{
+    CKAN,
+    bulk::{BulkProgress, Operation},
+    params::{ObjectType, Role},
+};
+use serde_json::json;
+use std::sync::Mutex;
+
+#[test]
+fn operation_action_and_body() {
+    let operation = Operation::MemberCreate {
+        id: "my-group".to_string(),
+        object: "my-dataset".to_string(),
+        object_type: ObjectType::Package,
+        capacity: Role::Member,
+    };
+    assert_eq!(operation.action(), "member_create");
+    assert_eq!(operation.body()["object_type"], "package");
+    assert_eq!(operation.body()["capacity"], "member");
+    assert_eq!(
+        Operation::PackageDelete {
+            id: "my-dataset".to_string()
+        }
+        .body(),
+        json!({ "id": "my-dataset" })
+    );
+}
+
+#[tokio::test]
+async fn bulk_continues_on_error_and_reports_progress() {
+    // Nothing listens on port 1, so every operation fails to connect.
+    let ckan = CKAN::builder().url("http://127.0.0.1:1").build();
+    let progress: Mutex<Vec<BulkProgress>> = Mutex::new(Vec::new());
+    let operations =
+        (0..10).map(|i| Operation::PackagePatch(json!({ "id": format!("dataset-{i}") })));
+    let results = ckan
+        .bulk()
+        .operations(operations)
+        .concurrency(3)
+        .on_progress(&|event| progress.lock().unwrap().push(*event))
+        .call()
+        .await;
+    assert_eq!(results.len(), 10);
+    assert!(results.iter().enumerate().all(|(i, item)| item.index == i));
+    assert!(results.iter().all(|item| item.result.is_err()));
+    let progress = progress.into_inner().unwrap();
+    assert_eq!(progress.len(), 10);
+    assert_eq!(progress.last().unwrap().completed, 10);
+    assert!(
+        progress
+            .iter()
+            .all(|event| event.total == 10 && !event.success)
+    );
+}
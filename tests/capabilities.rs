@@ -0,0 +1,50 @@
+mod common;
+
+use ckanaction::{CKAN, CKANError, version::Version};
+use serde_json::json;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+#[tokio::test]
+async fn discover_and_require_extensions() {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let counter = requests.clone();
+    let url = common::serve(move |action, body| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        match (action, body["name"].as_str()) {
+            ("status_show", _) => json!({
+                "success": true,
+                "result": { "ckan_version": "2.11.1", "extensions": ["datastore", "scheming_datasets"] },
+            }),
+            ("help_show", Some("xloader_submit")) => json!({ "success": true, "result": "..." }),
+            _ => json!({
+                "success": false,
+                "error": { "__type": "Not Found Error", "message": "Not found" },
+            }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+
+    let capabilities = ckan.capabilities().await.unwrap();
+    assert_eq!(capabilities.version, Some(Version::new(2, 11, 1)));
+    assert!(capabilities.has_extension("datastore"));
+    assert!(!capabilities.has_extension("harvest"));
+    assert_eq!(ckan.server_version().await.unwrap(), Version::new(2, 11, 1));
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+    ckan.require_extension("datastore", "datastore_search")
+        .await
+        .unwrap();
+    ckan.require_extension("xloader", "xloader_submit")
+        .await
+        .unwrap();
+    let error = ckan
+        .require_extension("harvest", "harvest_source_list")
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CKANError::Unsupported(_)), "{error:?}");
+    assert!(!ckan.has_action("harvest_source_list").await.unwrap());
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+}
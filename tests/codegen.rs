@@ -0,0 +1,123 @@
+use ckanaction::{
+    codegen::rust_types,
+    scheming::{Schema, SchemingFields},
+};
+use serde_json::json;
+
+mod generated {
+    include!("fixtures/scheming/dataset.rs");
+}
+
+use generated::{DatasetFields, DatasetFrequency, DatasetResourceFields, DatasetThemes};
+
+#[test]
+fn generate_structs_and_choice_enums() {
+    let schema = Schema::from_yaml_str(
+        r#"
+dataset_type: dataset
+dataset_fields:
+  - field_name: title
+    required: true
+  - field_name: frequency
+    preset: select
+    choices:
+      - value: daily
+      - value: 2-weekly
+  - field_name: themes
+    preset: multiple_checkbox
+    choices:
+      - value: air
+  - field_name: record-count
+    validators: ignore_missing int_validator
+  - field_name: type
+resource_fields:
+  - field_name: checked
+    preset: checkbox
+"#,
+    )
+    .unwrap();
+    let code = rust_types(&schema);
+    assert!(code.contains("pub struct DatasetFields {"));
+    assert!(!code.contains("pub title:"));
+    assert!(code.contains(
+        "pub enum DatasetFrequency {\n    Daily,\n    V2Weekly,\n    /// Any value not among the choices of the schema.\n    Other(String),\n}"
+    ));
+    assert!(code.contains("pub frequency: Option<DatasetFrequency>,"));
+    assert!(code.contains("pub themes: Option<Vec<DatasetThemes>>,"));
+    assert!(code.contains(
+        "#[serde(rename = \"record-count\")]\n    #[serde(deserialize_with = \"ckanaction::scheming::string_or_value\")]\n    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub record_count: Option<i64>,"
+    ));
+    assert!(code.contains("pub struct DatasetResourceFields {"));
+    assert!(code.contains("pub checked: Option<bool>,"));
+    assert!(
+        code.contains("impl ckanaction::scheming::SchemingFields for DatasetResourceFields {}")
+    );
+}
+
+#[test]
+fn compile_the_generated_fixture() {
+    let schema = Schema::from_path("tests/fixtures/scheming/dataset.yaml").unwrap();
+    let code = rust_types(&schema);
+    assert_eq!(code, include_str!("fixtures/scheming/dataset.rs"));
+    let fields = DatasetFields {
+        frequency: Some(DatasetFrequency::Other2),
+        themes: Some(vec![DatasetThemes::Air, DatasetThemes::Air2]),
+        record_count: Some(3),
+        record_count_2: Some("three".to_string()),
+        r#try: Some("yes".to_string()),
+        r#override: None,
+        crate_: Some("serde".to_string()),
+        field_: Some("dash".to_string()),
+    };
+    let custom_fields = fields.to_custom_fields().unwrap();
+    assert_eq!(
+        custom_fields,
+        json!({
+            "frequency": "other",
+            "themes": ["air", "Air"],
+            "record-count": 3,
+            "record_count": "three",
+            "try": "yes",
+            "crate": "serde",
+            "-": "dash",
+        })
+    );
+    let fields =
+        DatasetFields::from_custom_fields(json!({ "frequency": "hourly" }).as_object().unwrap())
+            .unwrap();
+    assert_eq!(
+        fields.frequency,
+        Some(DatasetFrequency::Other("hourly".to_string()))
+    );
+    // Extras come back from `package_show` as strings.
+    let fields = DatasetFields::from_custom_fields(
+        json!({ "record-count": "42", "record_count": "42" })
+            .as_object()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(fields.record_count, Some(42));
+    assert_eq!(fields.record_count_2.as_deref(), Some("42"));
+    let resource = DatasetResourceFields::from_custom_fields(
+        json!({ "checked": "True" }).as_object().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(resource.checked, Some(true));
+    assert!(
+        DatasetFields::from_custom_fields(json!({ "record-count": "many" }).as_object().unwrap())
+            .is_err()
+    );
+    assert_eq!(
+        DatasetFrequency::from("2-weekly"),
+        DatasetFrequency::V2Weekly
+    );
+    assert_eq!(DatasetFrequency::Daily.to_string(), "daily");
+    let resource = DatasetResourceFields {
+        checked: Some(true),
+        r#typeof: Some(json!({ "a": 1 })),
+    };
+    assert_eq!(
+        resource.to_custom_fields().unwrap(),
+        json!({ "checked": true, "typeof": { "a": 1 } })
+    );
+}
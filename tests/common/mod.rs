@@ -0,0 +1,71 @@
+//! A stand-in CKAN server for tests that need responses.
+
+#![allow(dead_code)]
+
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+};
+
+/// Serve the action API on a local port and return its URL. `respond` gets the action name and
+/// the JSON body (`null` for GET requests) and returns the full response document.
+pub fn serve(respond: impl Fn(&str, Value) -> Value + Send + 'static) -> String {
+    serve_authorized(move |_, action, body| (200, respond(action, body)))
+}
+
+/// Like [`serve`], but `respond` also gets the `Authorization` header and returns the status
+/// code along with the response document.
+pub fn serve_authorized(
+    respond: impl Fn(Option<&str>, &str, Value) -> (u16, Value) + Send + 'static,
+) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length = 0;
+            let mut authorization = None;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    length = value.trim().parse().unwrap();
+                }
+                if let Some((name, value)) = header.split_once(':')
+                    && name.eq_ignore_ascii_case("authorization")
+                {
+                    authorization = Some(value.trim().to_string());
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default();
+            let action = path.rsplit('/').next().unwrap_or_default();
+            let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+            let (status, response) = respond(authorization.as_deref(), action, body);
+            let reason = match status {
+                200 => "OK",
+                401 => "Unauthorized",
+                403 => "Forbidden",
+                _ => "Error",
+            };
+            let response = response.to_string();
+            write!(
+                stream,
+                "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                response.len()
+            )
+            .unwrap();
+        }
+    });
+    url
+}
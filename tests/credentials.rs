@@ -0,0 +1,85 @@
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    credentials::{CommandToken, FileToken},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+fn ok() -> serde_json::Value {
+    json!({ "success": true, "result": {} })
+}
+
+#[tokio::test]
+async fn read_a_rotated_token_file_on_every_request() {
+    let path = std::env::temp_dir().join(format!("ckanaction-token-{}", std::process::id()));
+    std::fs::write(&path, "first\n").unwrap();
+    let seen = Arc::new(Mutex::new(vec![]));
+    let headers = seen.clone();
+    let url = common::serve_authorized(move |authorization, _, _| {
+        headers
+            .lock()
+            .unwrap()
+            .push(authorization.map(str::to_string));
+        (200, ok())
+    });
+    let ckan = CKAN::builder()
+        .url(&url)
+        .token("ignored")
+        .credentials(Arc::new(FileToken::new(&path)))
+        .build();
+    assert_eq!(ckan.token().await.unwrap().as_deref(), Some("first"));
+    ckan.status_show().await.unwrap();
+    std::fs::write(&path, "second").unwrap();
+    ckan.status_show().await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(
+        ckan.status_show().await,
+        Err(CKANError::IoError(_))
+    ));
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [Some("first".to_string()), Some("second".to_string())]
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn run_the_token_command_again_when_the_token_is_rejected() {
+    let path = std::env::temp_dir().join(format!("ckanaction-command-{}", std::process::id()));
+    std::fs::write(&path, "a").unwrap();
+    let seen = Arc::new(Mutex::new(vec![]));
+    let headers = seen.clone();
+    let url = common::serve_authorized(move |authorization, _, _| {
+        let mut seen = headers.lock().unwrap();
+        seen.push(authorization.unwrap_or_default().to_string());
+        match (seen.len(), authorization) {
+            (1, _) | (_, Some("b")) => (200, ok()),
+            _ => (
+                401,
+                json!({ "success": false, "error": { "message": "Unauthorized" } }),
+            ),
+        }
+    });
+    let command = CommandToken::new("cat", [path.to_str().unwrap()]);
+    let ckan = CKAN::builder()
+        .url(&url)
+        .credentials(Arc::new(command))
+        .build();
+    ckan.status_show().await.unwrap();
+    std::fs::write(&path, "b").unwrap();
+    let response = ckan.status_show().await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(response["success"], true);
+    assert_eq!(*seen.lock().unwrap(), ["a", "a", "b"]);
+
+    let failing = CKAN::builder()
+        .url(&url)
+        .credentials(Arc::new(CommandToken::new("false", [] as [&str; 0])))
+        .build();
+    assert!(matches!(
+        failing.status_show().await,
+        Err(CKANError::CredentialError(_))
+    ));
+}
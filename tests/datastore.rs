@@ -0,0 +1,131 @@
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    datastore::{self, Field, infer_fields},
+    params::DatastoreType,
+};
+use serde_json::{Map, Value, json};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+
+#[test]
+fn infer_datastore_field_types() {
+    let records: Vec<Map<String, Value>> = serde_json::from_value(json!([
+        { "count": "1", "big": "1", "ratio": "1", "day": "2024-01-31", "at": "2024-01-31", "zip": "0123", "ok": "true", "tags": ["a"] },
+        { "count": 2, "big": "9999999999", "ratio": "0.5", "day": "2024-02-01", "at": "2024-02-01T10:00:00Z", "zip": "4567", "ok": false, "empty": null },
+    ]))
+    .unwrap();
+    let types: Vec<(String, DatastoreType)> = infer_fields(&records)
+        .into_iter()
+        .map(|field| (field.id, field._type))
+        .collect();
+    assert_eq!(
+        types,
+        [
+            ("at".to_string(), DatastoreType::Timestamp),
+            ("big".to_string(), DatastoreType::BigInt),
+            ("count".to_string(), DatastoreType::Int),
+            ("day".to_string(), DatastoreType::Date),
+            ("ok".to_string(), DatastoreType::Bool),
+            ("ratio".to_string(), DatastoreType::Numeric),
+            ("tags".to_string(), DatastoreType::Json),
+            ("zip".to_string(), DatastoreType::Text),
+            ("empty".to_string(), DatastoreType::Text),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn import_csv_in_batches_and_resume() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let failed = AtomicBool::new(false);
+    let url = common::serve(move |action, body| {
+        sink.lock()
+            .unwrap()
+            .push((action.to_string(), body.clone()));
+        match action {
+            "status_show" => json!({ "success": true, "result": { "extensions": ["datastore"] } }),
+            "datastore_upsert"
+                if body["records"][0]["name"] == "c" && !failed.swap(true, Ordering::SeqCst) =>
+            {
+                json!({ "success": false, "error": { "__type": "Validation Error" } })
+            }
+            _ => json!({ "success": true, "result": {} }),
+        }
+    });
+    let path =
+        std::env::temp_dir().join(format!("ckanaction-datastore-{}.csv", std::process::id()));
+    std::fs::write(
+        &path,
+        "name,value\na,1.10000000000000000001\nb,\nc,3\nd,4.5\ne,5\n",
+    )
+    .unwrap();
+    let ckan = CKAN::builder().url(&url).build();
+
+    let error = datastore::load(&ckan)
+        .resource_id("r1")
+        .path(path.clone())
+        .batch_size(2)
+        .call()
+        .await
+        .unwrap_err();
+    let CKANError::LoadInterrupted { rows, .. } = error else {
+        panic!("expected an interrupted load, got {error:?}");
+    };
+    assert_eq!(rows, 2);
+    {
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent[1].0, "datastore_create");
+        assert_eq!(
+            sent[1].1["fields"],
+            json!([Field::new("name", "text"), Field::new("value", "numeric")])
+        );
+        assert_eq!(sent[2].0, "datastore_upsert");
+        assert_eq!(sent[2].1["method"], "insert");
+        assert_eq!(
+            sent[2].1["records"],
+            json!([{ "name": "a", "value": "1.10000000000000000001" }, { "name": "b", "value": null }]),
+            "numeric values are sent as text to keep their precision"
+        );
+    }
+
+    let report = datastore::load(&ckan)
+        .resource_id("r1")
+        .path(path)
+        .batch_size(2)
+        .skip_rows(rows)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!((report.rows, report.batches), (5, 2));
+    let sent = sent.lock().unwrap();
+    let actions: Vec<&str> = sent[4..]
+        .iter()
+        .map(|(action, _)| action.as_str())
+        .collect();
+    assert_eq!(actions, ["datastore_upsert", "datastore_upsert"]);
+}
+
+#[tokio::test]
+async fn reject_json_lines_that_are_not_objects() {
+    let url = common::serve(|action, _| match action {
+        "status_show" => json!({ "success": true, "result": { "extensions": ["datastore"] } }),
+        _ => json!({ "success": true, "result": {} }),
+    });
+    let path =
+        std::env::temp_dir().join(format!("ckanaction-datastore-{}.jsonl", std::process::id()));
+    std::fs::write(&path, "{\"a\": 1}\n[2]\n").unwrap();
+    let ckan = CKAN::builder().url(&url).build();
+    let error = datastore::load(&ckan)
+        .resource_id("r1")
+        .path(path.clone())
+        .call()
+        .await
+        .unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(error, CKANError::SerdeJsonError(_)), "{error:?}");
+}
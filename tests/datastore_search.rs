@@ -0,0 +1,181 @@
+mod common;
+
+use ckanaction::CKAN;
+use futures::TryStreamExt;
+use serde_json::json;
+
+fn serve_table() -> String {
+    common::serve(|_, body| {
+        assert_eq!(body["sort"], "_id");
+        let rows = [
+            ("north", 1.5, "2024-01-01"),
+            ("south", 2.0, "2024-01-02"),
+            ("east", 3.5, "2024-01-03"),
+        ];
+        let offset = body["offset"].as_u64().unwrap() as usize;
+        let limit = body["limit"].as_u64().unwrap() as usize;
+        let records: Vec<_> = rows
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(limit)
+            .map(|(id, (station, value, day))| {
+                json!({ "_id": id + 1, "station": station, "value": value, "day": day })
+            })
+            .collect();
+        json!({
+            "success": true,
+            "result": {
+                "fields": [
+                    { "id": "_id", "type": "int" },
+                    { "id": "station", "type": "text" },
+                    { "id": "value", "type": "numeric" },
+                    { "id": "day", "type": "date" },
+                ],
+                "records": records,
+                "total": rows.len(),
+            },
+        })
+    })
+}
+
+#[derive(Debug, PartialEq, serde::Deserialize)]
+struct Row {
+    station: String,
+    value: f64,
+}
+
+#[tokio::test]
+async fn stream_typed_rows_and_export_tables() {
+    let url = serve_table();
+    let ckan = CKAN::builder().url(&url).build();
+    let rows: Vec<Row> = ckan
+        .datastore_records::<Row>()
+        .resource_id("r1")
+        .page_size(2)
+        .call()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(
+        rows[2],
+        Row {
+            station: "east".to_string(),
+            value: 3.5
+        }
+    );
+
+    let table = ckan
+        .datastore_table()
+        .resource_id("r1")
+        .page_size(2)
+        .call()
+        .await
+        .unwrap();
+    let mut csv = vec![];
+    table.write_csv(&mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "_id,station,value,day\n1,north,1.5,2024-01-01\n2,south,2.0,2024-01-02\n3,east,3.5,2024-01-03\n"
+    );
+
+    #[cfg(feature = "arrow")]
+    {
+        use arrow_schema::DataType;
+        let batch = table.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        let types: Vec<DataType> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.data_type().clone())
+            .collect();
+        assert_eq!(
+            types,
+            [
+                DataType::Int64,
+                DataType::Utf8,
+                DataType::Float64,
+                DataType::Date32
+            ]
+        );
+    }
+
+    #[cfg(feature = "parquet")]
+    {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        let path = std::env::temp_dir().join(format!(
+            "ckanaction-datastore-search-{}.parquet",
+            std::process::id()
+        ));
+        table
+            .write_parquet(std::fs::File::create(&path).unwrap())
+            .unwrap();
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 3);
+    }
+}
+
+#[tokio::test]
+async fn repeat_the_search_on_every_page() {
+    let url = common::serve(|action, body| {
+        assert_eq!(
+            action, "datastore_search",
+            "pages are not fetched from _links.next"
+        );
+        assert_eq!(body["resource_id"], "r1");
+        assert_eq!(body["filters"], json!({ "station": "north" }));
+        assert_eq!(body["sort"], "station desc");
+        assert_eq!(body["limit"], 1);
+        let records = match body["offset"].as_u64().unwrap() {
+            0 => json!([{ "_id": 1, "count": "9007199254740993" }]),
+            1 => json!([{ "_id": 2, "count": 9007199254740993_i64 }]),
+            offset => panic!("unexpected offset {offset}"),
+        };
+        json!({
+            "success": true,
+            "result": {
+                "fields": [{ "id": "_id", "type": "int" }, { "id": "count", "type": "int8" }],
+                "records": records,
+                "total": 2,
+                "_links": { "next": "/api/3/action/datastore_search?offset=1" },
+            },
+        })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let table = ckan
+        .datastore_table()
+        .resource_id("r1")
+        .filters(json!({ "station": "north" }))
+        .sort("station desc")
+        .page_size(1)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(table.records.len(), 2);
+
+    #[cfg(feature = "arrow")]
+    {
+        use arrow_array::{Array, Int64Array};
+        let batches: Vec<_> = ckan
+            .datastore_record_batches()
+            .resource_id("r1")
+            .filters(json!({ "station": "north" }))
+            .sort("station desc")
+            .page_size(1)
+            .call()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+        for batch in &batches {
+            let counts = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            assert_eq!(counts.value(0), 9_007_199_254_740_993);
+        }
+    }
+}
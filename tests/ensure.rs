@@ -0,0 +1,77 @@
+mod common;
+
+use ckanaction::{CKAN, CKANError, ensure::Ensured, ensure::is_subset};
+use serde_json::json;
+
+#[test]
+fn subset_ignores_fields_added_by_ckan() {
+    let current = json!({
+        "id": "5f8c",
+        "name": "air-quality",
+        "notes": null,
+        "tags": [
+            { "id": "1", "name": "health", "display_name": "health" },
+            { "id": "2", "name": "air", "display_name": "air" },
+        ],
+    });
+    assert!(is_subset(
+        &json!({ "name": "air-quality", "notes": "", "tags": [{ "name": "air" }, { "name": "health" }] }),
+        &current
+    ));
+    assert!(!is_subset(
+        &json!({ "tags": [{ "name": "air" }] }),
+        &current
+    ));
+    assert!(!is_subset(
+        &json!({ "tags": [{ "name": "air" }, { "name": "air" }] }),
+        &current
+    ));
+    assert!(!is_subset(&json!({ "title": "Air quality" }), &current));
+}
+
+#[tokio::test]
+async fn create_update_or_leave_entities() {
+    let url = common::serve(|action, body| {
+        let group = json!({ "id": "g1", "name": "air", "title": "Air" });
+        match (action, body["id"].as_str()) {
+            ("group_show", Some("air")) => json!({ "success": true, "result": group }),
+            ("group_show", Some("locked")) => json!({
+                "success": false,
+                "error": { "__type": "Authorization Error" },
+            }),
+            ("group_show", _) => json!({
+                "success": false,
+                "error": { "__type": "Not Found Error" },
+            }),
+            ("group_create", _) => json!({ "success": true, "result": body }),
+            ("group_patch", _) => {
+                assert_eq!(body, json!({ "id": "g1", "title": "Air quality" }));
+                json!({ "success": true, "result": { "id": "g1", "name": "air", "title": "Air quality" } })
+            }
+            _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let ensure = |name: &'static str, title: &'static str| {
+        ckan.ensure_group()
+            .name(name)
+            .fields(json!({ "title": title }))
+            .call()
+    };
+    assert!(matches!(
+        ensure("water", "Water").await.unwrap(),
+        Ensured::Created(created) if created["title"] == "Water"
+    ));
+    assert!(matches!(
+        ensure("air", "Air").await.unwrap(),
+        Ensured::Unchanged(_)
+    ));
+    assert!(matches!(
+        ensure("air", "Air quality").await.unwrap(),
+        Ensured::Updated { changed, .. } if changed == ["title"]
+    ));
+    assert!(matches!(
+        ensure("locked", "Locked").await,
+        Err(CKANError::ActionError(error)) if error["__type"] == "Authorization Error"
+    ));
+}
@@ -0,0 +1,150 @@
+// Generated by ckanaction from the `dataset` scheming schema. Do not edit.
+
+/// Choices of `frequency`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DatasetFrequency {
+    Daily,
+    V2Weekly,
+    Other2,
+    /// Any value not among the choices of the schema.
+    Other(String),
+}
+
+impl DatasetFrequency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Daily => "daily",
+            Self::V2Weekly => "2-weekly",
+            Self::Other2 => "other",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<&str> for DatasetFrequency {
+    fn from(value: &str) -> Self {
+        match value {
+            "daily" => Self::Daily,
+            "2-weekly" => Self::V2Weekly,
+            "other" => Self::Other2,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for DatasetFrequency {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for DatasetFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for DatasetFrequency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DatasetFrequency {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(<String as serde::Deserialize>::deserialize(deserializer)?))
+    }
+}
+
+/// Choices of `themes`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DatasetThemes {
+    Air,
+    Air2,
+    /// Any value not among the choices of the schema.
+    Other(String),
+}
+
+impl DatasetThemes {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Air => "air",
+            Self::Air2 => "Air",
+            Self::Other(value) => value,
+        }
+    }
+}
+
+impl From<&str> for DatasetThemes {
+    fn from(value: &str) -> Self {
+        match value {
+            "air" => Self::Air,
+            "Air" => Self::Air2,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for DatasetThemes {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl std::fmt::Display for DatasetThemes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for DatasetThemes {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DatasetThemes {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(<String as serde::Deserialize>::deserialize(deserializer)?))
+    }
+}
+
+/// Custom fields of `dataset` datasets.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DatasetFields {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<DatasetFrequency>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub themes: Option<Vec<DatasetThemes>>,
+    #[serde(rename = "record-count")]
+    #[serde(deserialize_with = "ckanaction::scheming::string_or_value")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_count: Option<i64>,
+    #[serde(rename = "record_count")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_count_2: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#try: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#override: Option<String>,
+    #[serde(rename = "crate")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crate_: Option<String>,
+    #[serde(rename = "-")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_: Option<String>,
+}
+
+impl ckanaction::scheming::SchemingFields for DatasetFields {}
+
+/// Custom fields of the resources of `dataset` datasets.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DatasetResourceFields {
+    #[serde(deserialize_with = "ckanaction::scheming::string_or_value")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#typeof: Option<serde_json::Value>,
+}
+
+impl ckanaction::scheming::SchemingFields for DatasetResourceFields {}
@@ -30,6 +30,49 @@ async fn status_show() -> Result<(), CKANError> {
 #[tokio::test]
 async fn print_ckan_struct_with_debug() -> Result<(), CKANError> {
     let ckan = get_ckan_builder().await;
-    assert_eq!(format!("{ckan:?}"), r#"CKAN { url: "", token: Some("") }"#);
+    assert_eq!(
+        format!("{ckan:?}"),
+        r#"CKAN { url: "", credentials: RwLock { data: Some(StaticToken(..)), poisoned: false, .. }, headers: None, dry_run: None, schema: None, version: OnceLock(<uninit>), capabilities: Cache { .. } }"#
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_run_records_mutating_actions() -> Result<(), CKANError> {
+    // Nothing listens on port 1, so any request that reaches the network fails.
+    let ckan = CKAN::builder()
+        .url("http://127.0.0.1:1")
+        .dry_run(true)
+        .build();
+    let response = ckan
+        .package_delete()
+        .id("my-dataset".to_string())
+        .call()
+        .await?;
+    assert_eq!(response["success"], true);
+    assert_eq!(response["result"]["id"], "my-dataset");
+    assert!(
+        ckan.package_show()
+            .id("my-dataset".to_string())
+            .call()
+            .await
+            .is_err()
+    );
+    let journal = ckan.dry_run_journal();
+    assert_eq!(journal.len(), 1);
+    assert_eq!(journal[0].action, "package_delete");
+    assert_eq!(
+        journal[0].endpoint,
+        "http://127.0.0.1:1/api/3/action/package_delete"
+    );
+    assert!(!ckanaction::dry_run::is_read_action("dataset_purge"));
+    assert!(ckanaction::dry_run::is_read_action(
+        "organization_list_for_user"
+    ));
+    assert!(ckanaction::dry_run::is_read_action("site_read"));
+    assert!(ckanaction::dry_run::is_read_action("activity_diff"));
+    assert!(!ckanaction::dry_run::is_read_action(
+        "resource_view_reorder"
+    ));
     Ok(())
 }
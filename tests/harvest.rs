@@ -0,0 +1,81 @@
+mod common;
+
+use ckanaction::{CKAN, CKANError, params::HarvestJobStatus};
+use serde_json::json;
+use std::{
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+#[tokio::test]
+async fn run_a_harvest_job_to_completion() {
+    let checks = Arc::new(AtomicUsize::new(0));
+    let counter = checks.clone();
+    let url = common::serve(move |action, body| {
+        let job = |status: &str, added: u64| {
+            json!({
+                "success": true,
+                "result": {
+                    "id": "job-1",
+                    "source_id": "csw",
+                    "status": status,
+                    "stats": { "added": added, "not modified": 1 },
+                },
+            })
+        };
+        match action {
+            "status_show" => json!({ "success": true, "result": { "extensions": ["harvest"] } }),
+            "harvest_job_create" => {
+                assert_eq!(body, json!({ "source_id": "csw", "run": true }));
+                job("Running", 0)
+            }
+            "harvest_job_show" => match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => job("Running", 2),
+                _ => job("Finished", 5),
+            },
+            _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let seen = Mutex::new(vec![]);
+    let job = ckan
+        .harvest_run()
+        .source_id("csw")
+        .poll_interval(Duration::from_millis(1))
+        .on_progress(&|job| seen.lock().unwrap().push(job.stats.added))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(job.status, HarvestJobStatus::Finished);
+    assert_eq!(job.stats.not_modified, 1);
+    assert_eq!(*seen.lock().unwrap(), [0, 2, 5]);
+    assert_eq!(checks.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn stop_waiting_for_a_hanging_harvest_job() {
+    let url = common::serve(|action, _| match action {
+        "status_show" => json!({ "success": true, "result": { "extensions": ["harvest"] } }),
+        "harvest_job_create" | "harvest_job_show" => json!({
+            "success": true,
+            "result": { "id": "job-1", "source_id": "csw", "status": "Running" },
+        }),
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let error = ckan
+        .harvest_run()
+        .source_id("csw")
+        .poll_interval(Duration::from_millis(1))
+        .timeout(Duration::from_millis(50))
+        .call()
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&error, CKANError::Timeout { what, .. } if what.contains("job-1")),
+        "{error:?}"
+    );
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use ckanaction::{CKAN, CKANError, jobs::QueueStats};
+use futures::StreamExt;
+use serde_json::json;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+#[tokio::test]
+async fn await_a_job_until_it_leaves_the_queue() {
+    let lists = Arc::new(AtomicUsize::new(0));
+    let counter = lists.clone();
+    let url = common::serve(move |action, body| {
+        let job = json!({
+            "id": "job-1",
+            "title": "Reindex",
+            "created": "2026-10-18T08:00:00",
+            "queue": "bulk",
+        });
+        match action {
+            // Finished and failed jobs are still shown.
+            "job_show" => json!({ "success": true, "result": job }),
+            "job_list" => {
+                assert_eq!(body["queues"], json!(["bulk"]));
+                match counter.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => json!({ "success": true, "result": [job] }),
+                    _ => json!({ "success": true, "result": [] }),
+                }
+            }
+            _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let job = ckan
+        .await_job()
+        .id("job-1")
+        .initial_interval(Duration::from_millis(1))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(job.title.as_deref(), Some("Reindex"));
+    assert_eq!(job.queue, "bulk");
+    assert_eq!(lists.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn stop_waiting_for_a_job_after_the_timeout() {
+    let url = common::serve(|action, _| {
+        let job = json!({ "id": "job-1", "title": null, "created": null, "queue": "default" });
+        match action {
+            "job_show" => json!({ "success": true, "result": job }),
+            "job_list" => json!({ "success": true, "result": [job] }),
+            _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let error = ckan
+        .await_job()
+        .id("job-1")
+        .initial_interval(Duration::from_millis(1))
+        .timeout(Duration::from_millis(50))
+        .call()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CKANError::Timeout { .. }), "{error:?}");
+}
+#[tokio::test]
+async fn stream_queue_statistics() {
+    let url = common::serve(|action, _| match action {
+        "job_list" => json!({
+            "success": true,
+            "result": [
+                { "id": "a", "title": null, "created": "2026-10-18T08:05:00", "queue": "default" },
+                { "id": "b", "title": null, "created": "2026-10-18T08:00:00", "queue": "default" },
+                { "id": "c", "title": null, "created": null, "queue": "bulk" },
+            ],
+        }),
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let snapshots: Vec<_> = ckan
+        .queue_stats()
+        .interval(Duration::from_millis(1))
+        .call()
+        .take(2)
+        .collect()
+        .await;
+    assert_eq!(snapshots.len(), 2);
+    assert_eq!(
+        snapshots[1].as_ref().unwrap(),
+        &[
+            QueueStats {
+                queue: "bulk".to_string(),
+                jobs: 1,
+                oldest: None,
+            },
+            QueueStats {
+                queue: "default".to_string(),
+                jobs: 2,
+                oldest: Some("2026-10-18T08:00:00".to_string()),
+            },
+        ]
+    );
+}
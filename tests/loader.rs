@@ -0,0 +1,110 @@
+mod common;
+
+use ckanaction::{CKAN, CKANError};
+use serde_json::json;
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+#[tokio::test]
+async fn load_resources_and_surface_loader_logs() {
+    let checks = Arc::new(AtomicUsize::new(0));
+    let counter = checks.clone();
+    let url = common::serve(move |action, body| match action {
+        "status_show" => json!({ "success": true, "result": { "extensions": ["xloader"] } }),
+        "resource_create" => {
+            json!({ "success": true, "result": { "id": "r1", "package_id": "d1" } })
+        }
+        "xloader_submit" => {
+            assert_ne!(
+                body["resource_id"], "r1",
+                "new uploads are queued by the loader"
+            );
+            json!({ "success": true, "result": true })
+        }
+        "xloader_status" if body["resource_id"] == "r1" => {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                0 => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+                1 => json!({ "success": true, "result": { "status": "running" } }),
+                _ => json!({ "success": true, "result": { "status": "complete", "job_id": "j1" } }),
+            }
+        }
+        // The clock of the server is behind: the new job looks older than the previous one.
+        "xloader_status" if body["resource_id"] == "r3" => {
+            match counter.fetch_add(1, Ordering::SeqCst) {
+                3 | 4 => json!({
+                    "success": true,
+                    "result": { "status": "complete", "job_id": "old", "last_updated": "2999-01-01T00:00:00" },
+                }),
+                _ => json!({
+                    "success": true,
+                    "result": { "status": "complete", "job_id": "new", "last_updated": "2000-01-01T00:00:00.000001" },
+                }),
+            }
+        }
+        "xloader_status" if body["resource_id"] == "r4" => {
+            json!({ "success": true, "result": { "status": "running" } })
+        }
+        "xloader_status" => json!({
+            "success": true,
+            "result": {
+                "status": "error",
+                "error": { "message": "Error with field \"date\"" },
+                "task_info": { "logs": [{ "level": "INFO", "message": "Fetching" }, { "level": "ERROR", "message": "Bad date" }] },
+            },
+        }),
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let path = std::env::temp_dir().join(format!("ckanaction-loader-{}.csv", std::process::id()));
+    std::fs::write(&path, "a,b\n1,2\n").unwrap();
+
+    let resource = ckan
+        .resource_create_and_load()
+        .package_id("d1")
+        .upload(path)
+        .format("CSV")
+        .poll_interval(Duration::from_millis(1))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(resource.id, "r1");
+    assert_eq!(checks.load(Ordering::SeqCst), 3);
+
+    let error = ckan
+        .datastore_load()
+        .resource_id("r2")
+        .poll_interval(Duration::from_millis(1))
+        .call()
+        .await
+        .unwrap_err();
+    let CKANError::LoadError { message, logs, .. } = error else {
+        panic!("expected a load error, got {error:?}");
+    };
+    assert_eq!(message, "Error with field \"date\"");
+    assert_eq!(logs, ["Fetching", "Bad date"]);
+
+    let status = ckan
+        .datastore_load()
+        .resource_id("r3")
+        .poll_interval(Duration::from_millis(1))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(status.job_id.as_deref(), Some("new"));
+    assert_eq!(checks.load(Ordering::SeqCst), 6);
+
+    let error = ckan
+        .datastore_load()
+        .resource_id("r4")
+        .poll_interval(Duration::from_millis(1))
+        .timeout(Duration::from_millis(50))
+        .call()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CKANError::Timeout { .. }), "{error:?}");
+}
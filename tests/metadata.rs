@@ -0,0 +1,193 @@
+mod common;
+
+use ckanaction::{
+    CKAN,
+    metadata::{FieldChange, RowStatus, diff_package, flatten_package},
+};
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+fn package() -> serde_json::Value {
+    json!({
+        "id": "b3c5c6f1",
+        "name": "air-quality",
+        "title": "Air: quality \"daily\"",
+        "private": false,
+        "num_tags": 2,
+        "tags": [{ "name": "air" }, { "name": "health", "vocabulary_id": "topics" }],
+        "extras": [{ "key": "source", "value": "sensors" }],
+        "resources": [{ "id": "r1" }],
+        "spatial_coverage": { "country": "CA" },
+    })
+}
+
+#[test]
+fn flatten_package_to_cells() {
+    let row = flatten_package(&package());
+    assert_eq!(row["title"], "Air: quality \"daily\"");
+    assert_eq!(row["private"], "false");
+    assert_eq!(row["tags"], "air;health");
+    assert_eq!(row["extras.source"], "sensors");
+    assert_eq!(row["spatial_coverage"], r#"{"country":"CA"}"#);
+    assert!(!row.contains_key("resources"));
+}
+
+#[test]
+fn diff_package_only_patches_changed_fields() {
+    let mut row = flatten_package(&package());
+    row.insert("private".to_string(), "true".to_string());
+    row.insert("tags".to_string(), "health; climate".to_string());
+    row.insert("extras.source".to_string(), String::new());
+    row.insert("num_tags".to_string(), "5".to_string());
+    let diff = diff_package(&package(), &row).unwrap();
+    assert_eq!(
+        diff.patch,
+        json!({
+            "id": "b3c5c6f1",
+            "private": true,
+            "tags": [{ "name": "health", "vocabulary_id": "topics" }, { "name": "climate" }],
+            "extras": [],
+        })
+    );
+    assert_eq!(
+        diff.changes[0],
+        FieldChange {
+            field: "extras.source".to_string(),
+            old: "sensors".to_string(),
+            new: String::new(),
+        }
+    );
+    assert_eq!(diff.changes.len(), 3);
+}
+
+#[test]
+fn diff_package_reports_invalid_cells() {
+    let row = BTreeMap::from([
+        ("private".to_string(), "maybe".to_string()),
+        ("spatial_coverage".to_string(), "{".to_string()),
+    ]);
+    let errors = diff_package(&package(), &row).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors[0].starts_with("private:"));
+}
+
+#[tokio::test]
+async fn export_search_results_page_by_page() {
+    let url = common::serve(|action, body| match action {
+        "package_search" => {
+            let results = match body["start"].as_i64() {
+                Some(0) => json!([{ "id": "1", "name": "a", "title": "A" }]),
+                _ => {
+                    json!([{ "id": "2", "name": "b", "extras": [{ "key": "source", "value": "x" }] }])
+                }
+            };
+            json!({ "success": true, "result": { "count": 2, "results": results } })
+        }
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let mut csv = vec![];
+    let written = ckan
+        .metadata_export()
+        .writer(&mut csv)
+        .rows(1)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(written, 2);
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "id,name,extras.source,title\n1,a,,A\n2,b,x,\n"
+    );
+}
+
+#[tokio::test]
+async fn import_rows_and_report_each_one() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let url = common::serve(move |action, body| {
+        sink.lock()
+            .unwrap()
+            .push((action.to_string(), body.clone()));
+        match (action, body["id"].as_str()) {
+            ("package_show", Some("gone")) => {
+                json!({ "success": false, "error": { "__type": "Not Found Error" } })
+            }
+            ("package_show", Some(id)) => {
+                json!({ "success": true, "result": { "id": id, "name": id, "title": "Old" } })
+            }
+            ("package_patch", Some("locked")) => json!({
+                "success": false,
+                "error": { "__type": "Authorization Error" },
+            }),
+            _ => json!({ "success": true, "result": body }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let csv = "id,title\nsame,Old\nchanged,New\nlocked,New\ngone,New\n";
+
+    let reports = ckan
+        .metadata_import()
+        .reader(csv.as_bytes())
+        .dry_run(true)
+        .call()
+        .await
+        .unwrap();
+    let statuses: Vec<&RowStatus> = reports.iter().map(|report| &report.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            &RowStatus::Unchanged,
+            &RowStatus::Planned,
+            &RowStatus::Planned,
+            &RowStatus::Failed(json!({ "__type": "Not Found Error" })),
+        ]
+    );
+    assert_eq!(
+        reports[1].changes,
+        [FieldChange {
+            field: "title".to_string(),
+            old: "Old".to_string(),
+            new: "New".to_string(),
+        }]
+    );
+    assert!(
+        sent.lock()
+            .unwrap()
+            .drain(..)
+            .all(|(action, _)| action == "package_show"),
+        "a dry run sends no patch"
+    );
+
+    let reports = ckan
+        .metadata_import()
+        .reader(csv.as_bytes())
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(reports[0].status, RowStatus::Unchanged);
+    assert_eq!(reports[1].status, RowStatus::Applied);
+    assert_eq!(
+        reports[2].status,
+        RowStatus::Failed(json!({ "__type": "Authorization Error" })),
+        "a failing row does not stop the import"
+    );
+    assert_eq!(reports[3].row, 4);
+    let patches: Vec<serde_json::Value> = sent
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(action, _)| action == "package_patch")
+        .map(|(_, body)| body.clone())
+        .collect();
+    assert_eq!(
+        patches,
+        [
+            json!({ "id": "changed", "title": "New" }),
+            json!({ "id": "locked", "title": "New" }),
+        ]
+    );
+}
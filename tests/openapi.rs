@@ -0,0 +1,32 @@
+//! Checks that `src/endpoints.rs` and `src/actions.rs` match the OpenAPI description of the action API in
+//! `docs/lib/openapi.yml`, so the spec and the Rust surface can not drift apart. To change an
+//! endpoint, edit the spec and run `cargo xtask openapi`.
+
+#[path = "../xtask/src/openapi.rs"]
+mod openapi;
+
+use std::path::Path;
+
+#[test]
+fn endpoints_match_the_openapi_spec() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let Ok(spec) = std::fs::read_to_string(root.join(openapi::SPEC)) else {
+        eprintln!("{} is not available, skipping", openapi::SPEC);
+        return;
+    };
+    let spec = serde_yaml::from_str(&spec).unwrap();
+    for (file, generated) in [
+        (openapi::ENDPOINTS, openapi::generate(&spec)),
+        (openapi::ACTIONS, openapi::generate_actions(&spec)),
+    ] {
+        let generated = openapi::rustfmt(&generated)
+            .expect("rustfmt is needed to check the endpoints, install it with `rustup component add rustfmt`");
+        let current = std::fs::read_to_string(root.join(file)).unwrap();
+        assert!(
+            current == generated,
+            "{}{file} does not match {}, run `cargo xtask openapi`",
+            openapi::diff(&current, &generated),
+            openapi::SPEC
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use ckanaction::params::{PackageSort, Role, ViewType};
+
+#[test]
+fn convert_strings_with_fallback() {
+    assert_eq!(ViewType::from("datatables_view"), ViewType::DatatablesView);
+    assert_eq!(
+        ViewType::from("my_custom_view"),
+        ViewType::Other("my_custom_view".to_string())
+    );
+    assert_eq!(PackageSort::TitleAsc.as_str(), "title_string asc");
+    assert_eq!(
+        serde_json::to_value(Role::Other("owner".to_string())).unwrap(),
+        "owner"
+    );
+    assert_eq!(
+        serde_json::from_value::<Role>("editor".into()).unwrap(),
+        Role::Editor
+    );
+}
+
+#[test]
+fn other_roles_are_not_ordered() {
+    let owner = Role::Other("owner".to_string());
+    assert_eq!(owner.partial_cmp(&Role::Admin), None);
+    assert_eq!(
+        owner.partial_cmp(&owner.clone()),
+        Some(std::cmp::Ordering::Equal)
+    );
+    assert_eq!(Role::parse("owner"), None);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ckanaction::{
+    CKAN,
+    permissions::{Access, Role},
+};
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+#[test]
+fn roles_are_ordered_by_permissions() {
+    assert!(Role::Admin > Role::Editor && Role::Editor > Role::Member);
+    assert_eq!(Role::parse("editor"), Some(Role::Editor));
+    assert_eq!(Role::parse("owner"), None);
+    assert_eq!(serde_json::to_value(Role::Admin).unwrap(), "admin");
+}
+
+#[test]
+fn effective_access_combines_roles() {
+    let outsider = Access {
+        private: true,
+        ..Default::default()
+    };
+    assert!(!outsider.can_read() && !outsider.can_edit());
+
+    let collaborator = Access {
+        private: true,
+        organization_role: Some(Role::Member),
+        collaborator_role: Some(Role::Editor),
+        ..Default::default()
+    };
+    assert_eq!(collaborator.role(), Some(Role::Editor));
+    assert!(collaborator.can_read() && collaborator.can_edit());
+    assert!(!collaborator.can_manage_collaborators());
+
+    let sysadmin = Access {
+        sysadmin: true,
+        private: true,
+        ..Default::default()
+    };
+    assert_eq!(sysadmin.role(), Some(Role::Admin));
+    assert!(sysadmin.can_manage_collaborators());
+}
+
+#[tokio::test]
+async fn sync_organization_members_like_the_portal_does() {
+    let applied = Arc::new(Mutex::new(vec![]));
+    let calls = applied.clone();
+    let url = common::serve(move |action, body| {
+        let result = match action {
+            "member_list" => {
+                assert_eq!(body, json!({ "id": "city", "object_type": "user" }));
+                json!([["u1", "user", "editor"], ["u2", "user", "member"]])
+            }
+            "user_list" => json!([{ "id": "u1", "name": "alice" }, { "id": "u2", "name": "bob" }]),
+            _ => {
+                calls.lock().unwrap().push((action.to_string(), body));
+                json!({})
+            }
+        };
+        json!({ "success": true, "result": result })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let results = ckan
+        .organization_members_sync()
+        .id("city")
+        .members(BTreeMap::from([("alice".to_string(), Role::Admin)]))
+        .call()
+        .await
+        .unwrap();
+    assert!(results.iter().all(|applied| applied.result.is_ok()));
+    assert_eq!(
+        *applied.lock().unwrap(),
+        [
+            (
+                "organization_member_create".to_string(),
+                json!({ "id": "city", "username": "alice", "role": "admin" })
+            ),
+            (
+                "organization_member_delete".to_string(),
+                json!({ "id": "city", "username": "bob" })
+            ),
+        ]
+    );
+}
@@ -0,0 +1,203 @@
+mod common;
+
+use ckanaction::{
+    CKAN,
+    params::Role,
+    portal::{Change, GroupKind, Plan, PortalConfig},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn load_yaml_and_toml_configs() {
+    let yaml = PortalConfig::from_yaml_str(
+        "
+licenses: [cc-by]
+organizations:
+  - name: city-of-example
+    title: City of Example
+    members:
+      alice: admin
+vocabularies:
+  - name: topics
+    tags: [air, water]
+",
+    )
+    .unwrap();
+    let toml = PortalConfig::from_toml_str(
+        r#"
+licenses = ["cc-by"]
+
+[[organizations]]
+name = "city-of-example"
+title = "City of Example"
+members = { alice = "admin" }
+
+[[vocabularies]]
+name = "topics"
+tags = ["air", "water"]
+"#,
+    )
+    .unwrap();
+    assert_eq!(yaml, toml);
+    assert!(!yaml.prune);
+    let organizations = yaml.organizations.as_ref().unwrap();
+    assert_eq!(organizations[0].fields["title"], "City of Example");
+    assert_eq!(
+        organizations[0].members.as_ref().unwrap()["alice"],
+        Role::Admin
+    );
+    assert_eq!(
+        yaml.groups, None,
+        "a section that is left out is not managed"
+    );
+}
+
+#[test]
+fn plan_changes_map_to_actions() {
+    let plan = Plan {
+        changes: vec![
+            Change::Update {
+                kind: GroupKind::Organization,
+                name: "city-of-example".to_string(),
+                fields: json!({ "title": "City of Example" })
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            },
+            Change::SetMember {
+                kind: GroupKind::Group,
+                group: "transport".to_string(),
+                username: "bob".to_string(),
+                role: Role::Editor,
+                previous: Some(Role::Member),
+            },
+        ],
+        missing_licenses: vec!["odc-odbl".to_string()],
+    };
+    assert_eq!(
+        plan.changes[0].action(),
+        (
+            "organization_patch".to_string(),
+            json!({ "id": "city-of-example", "title": "City of Example" })
+        )
+    );
+    assert_eq!(plan.changes[1].action().0, "group_member_create");
+    assert_eq!(
+        plan.to_string(),
+        "~ organization city-of-example (title)\n\
+         ~ member bob of group transport (member -> editor)\n\
+         ! license odc-odbl is not available\n"
+    );
+}
+
+#[tokio::test]
+async fn plan_and_apply_against_the_live_instance() {
+    let applied = Arc::new(Mutex::new(vec![]));
+    let calls = applied.clone();
+    let user_lists = Arc::new(Mutex::new(0));
+    let counter = user_lists.clone();
+    let url = common::serve(move |action, body| {
+        let result = match action {
+            "organization_list" => json!(["city-of-example", "old-org"]),
+            // Not in the configuration, so not pruned.
+            "group_list" => json!(["transport"]),
+            "vocabulary_list" => json!([{ "id": "v1", "name": "topics", "tags": [] }]),
+            "organization_show" => {
+                json!({ "id": "o1", "name": "city-of-example", "title": "City" })
+            }
+            "member_list" => {
+                assert_eq!(body["object_type"], "user");
+                match body["id"].as_str().unwrap() {
+                    "city-of-example" => {
+                        json!([["u1", "user", "editor"], ["u2", "user", "member"]])
+                    }
+                    // The creator became an admin of the new organization.
+                    _ => json!([["u9", "user", "admin"], ["u1", "user", "admin"]]),
+                }
+            }
+            "user_list" => {
+                *counter.lock().unwrap() += 1;
+                json!([
+                    { "id": "u1", "name": "alice" },
+                    { "id": "u2", "name": "bob" },
+                    { "id": "u9", "name": "harvester" },
+                ])
+            }
+            "license_list" => json!([{ "id": "cc-by" }]),
+            _ => {
+                calls.lock().unwrap().push((action.to_string(), body));
+                json!({})
+            }
+        };
+        json!({ "success": true, "result": result })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let config = PortalConfig::from_yaml_str(
+        "
+prune: true
+licenses: [cc-by, odc-odbl]
+organizations:
+  - name: city-of-example
+    title: City of Example
+    members:
+      alice: admin
+  - name: new-org
+    members:
+      alice: admin
+",
+    )
+    .unwrap();
+    let plan = ckan.portal_plan().config(&config).call().await.unwrap();
+    assert_eq!(
+        plan.to_string(),
+        "+ organization new-org\n\
+         ~ organization city-of-example (title)\n\
+         ~ member alice of organization city-of-example (editor -> admin)\n\
+         + member alice of organization new-org as admin\n\
+         - member bob of organization city-of-example\n\
+         - creator of organization new-org unless a declared member\n\
+         - organization old-org\n\
+         ! license odc-odbl is not available\n"
+    );
+    assert_eq!(
+        *user_lists.lock().unwrap(),
+        1,
+        "members are resolved in one call"
+    );
+    let results = ckan.portal_apply().plan(&plan).call().await;
+    assert!(results.iter().all(|applied| applied.result.is_ok()));
+    assert_eq!(
+        *applied.lock().unwrap(),
+        [
+            (
+                "organization_create".to_string(),
+                json!({ "name": "new-org" })
+            ),
+            (
+                "organization_patch".to_string(),
+                json!({ "id": "city-of-example", "title": "City of Example" })
+            ),
+            (
+                "organization_member_create".to_string(),
+                json!({ "id": "city-of-example", "username": "alice", "role": "admin" })
+            ),
+            (
+                "organization_member_create".to_string(),
+                json!({ "id": "new-org", "username": "alice", "role": "admin" })
+            ),
+            (
+                "organization_member_delete".to_string(),
+                json!({ "id": "city-of-example", "username": "bob" })
+            ),
+            (
+                "organization_member_delete".to_string(),
+                json!({ "id": "new-org", "username": "harvester" })
+            ),
+            (
+                "organization_delete".to_string(),
+                json!({ "id": "old-org" })
+            ),
+        ]
+    );
+}
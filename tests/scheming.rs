@@ -0,0 +1,92 @@
+use ckanaction::{CKAN, CKANError, scheming::Schema};
+use serde_json::json;
+
+const SCHEMA: &str = r#"
+scheming_version: 2
+dataset_type: dataset
+dataset_fields:
+  - field_name: title
+    required: true
+  - field_name: name
+    preset: dataset_slug
+    required: true
+  - field_name: frequency
+    preset: select
+    choices:
+      - value: daily
+      - value: monthly
+  - field_name: reference
+    form_attrs:
+      pattern: "[A-Z]{3}-[0-9]+"
+resource_fields:
+  - field_name: url
+    required: true
+  - field_name: rows
+    validators: ignore_missing int_validator
+"#;
+
+#[test]
+fn validate_package_payloads() {
+    let schema = Schema::from_yaml_str(SCHEMA).unwrap();
+    let package = json!({
+        "name": "Air Quality",
+        "frequency": "weekly",
+        "reference": "abc-1",
+        "resources": [{ "url": "https://example.org/a.csv" }, { "rows": "many" }],
+    });
+    let errors = schema.validate_package(&package, false);
+    assert_eq!(
+        errors.to_value(),
+        json!({
+            "__type": "Validation Error",
+            "title": ["Missing value"],
+            "name": ["Must be purely lowercase alphanumeric (ascii) characters and these symbols: -_"],
+            "frequency": ["unexpected choice \"weekly\""],
+            "reference": ["Value does not match the pattern \"[A-Z]{3}-[0-9]+\""],
+            "resources": [{}, { "url": ["Missing value"], "rows": ["Invalid integer"] }],
+        })
+    );
+
+    let patch = json!({ "id": "air-quality", "frequency": "daily", "reference": "ABC-1" });
+    assert!(schema.validate_package(&patch, true).is_empty());
+    assert!(!schema.validate_package(&patch, false).is_empty());
+    assert!(
+        schema
+            .validate_action("package_patch", &json!({ "title": "" }))
+            .fields
+            .contains_key("title")
+    );
+    assert!(
+        schema
+            .validate_action("package_create", &json!({ "type": "showcase" }))
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn reject_invalid_payloads_before_sending() {
+    let ckan = CKAN::builder()
+        .url("http://127.0.0.1:1")
+        .dry_run(true)
+        .schema(Schema::from_yaml_str(SCHEMA).unwrap())
+        .build();
+    let error = ckan
+        .package_create()
+        .name("air-quality")
+        .private(false)
+        .call()
+        .await
+        .unwrap_err();
+    let CKANError::ActionError(error) = error else {
+        panic!("expected a validation error, got {error:?}");
+    };
+    assert_eq!(error["title"], json!(["Missing value"]));
+    ckan.package_create()
+        .name("air-quality")
+        .title("Air quality")
+        .private(false)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(ckan.dry_run_journal().len(), 1);
+}
@@ -0,0 +1,93 @@
+use ckanaction::search::{Query, SearchQuery, SearchResult, escape};
+
+#[test]
+fn escape_lucene_special_characters() {
+    assert_eq!(escape(r#"a:b (c) "d" e/f"#), r#"a\:b\ \(c\)\ \"d\"\ e\/f"#);
+}
+
+#[test]
+fn render_field_values_as_phrases() {
+    let query = Query::field("title", r#"Budget: "2024" \ draft"#);
+    assert_eq!(query.to_string(), r#"title:"Budget: \"2024\" \\ draft""#);
+}
+
+#[test]
+fn render_grouped_query() {
+    let query = SearchQuery::new()
+        .text("air quality?")
+        .filter(Query::organization("city-of-example"))
+        .filter(
+            Query::tag("health")
+                .or(Query::tag("environment"))
+                .and(Query::res_format("CSV").not()),
+        )
+        .filter(Query::between("num_resources", 1, 5))
+        .filter(Query::at_least("metadata_modified", "NOW-1YEAR"))
+        .filter(Query::wildcard("name", "air-*"));
+    assert_eq!(query.q().unwrap(), r#"air quality\?"#);
+    assert_eq!(
+        query.fq_list().unwrap(),
+        vec![
+            r#"organization:"city-of-example""#,
+            r#"((tags:"health" OR tags:"environment") AND (*:* NOT res_format:"CSV"))"#,
+            "num_resources:[1 TO 5]",
+            r#"metadata_modified:[NOW\-1YEAR TO *]"#,
+            r#"name:air\-*"#,
+        ]
+    );
+    assert!(
+        query
+            .fq()
+            .unwrap()
+            .contains(r#" AND num_resources:[1 TO 5] AND "#)
+    );
+}
+
+#[test]
+fn empty_groups_match_everything_or_nothing() {
+    assert_eq!(Query::all_of([]).to_string(), "*:*");
+    assert_eq!(Query::any_of([]).to_string(), "(*:* NOT *:*)");
+}
+
+#[test]
+fn empty_search_query_renders_nothing() {
+    let query = SearchQuery::new();
+    assert_eq!(query.q(), None);
+    assert_eq!(query.fq(), None);
+    assert_eq!(query.fq_list(), None);
+}
+
+#[test]
+fn parse_search_result_with_facets() {
+    let response = serde_json::json!({
+        "success": true,
+        "result": {
+            "count": 1,
+            "results": [{
+                "id": "b3c5c6f1",
+                "name": "air-quality",
+                "private": false,
+                "tags": [{ "name": "air" }],
+                "update_frequency": "daily",
+            }],
+            "facets": { "tags": { "air": 1 } },
+            "search_facets": {
+                "tags": {
+                    "title": "tags",
+                    "items": [{ "name": "air", "display_name": "Air", "count": 1 }],
+                },
+            },
+        },
+    });
+    let result = SearchResult::from_response(response).unwrap();
+    assert_eq!(result.count, 1);
+    assert_eq!(result.results[0].name, "air-quality");
+    assert_eq!(result.results[0].custom_fields["update_frequency"], "daily");
+    let item = &result.facets["tags"][0];
+    assert_eq!((item.display_name.as_str(), item.count), ("Air", 1));
+    assert_eq!(result.drill_down("tags", "air"), Some(Query::tag("air")));
+    assert_eq!(
+        SearchQuery::new().drill_down("tags", item).fq().unwrap(),
+        r#"tags:"air""#
+    );
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "spatial")]
+
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    actions::PackageSearch,
+    spatial::{self, BBox},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn validate_bounding_boxes_and_geometries() {
+    let bbox = BBox::new(3.31, 50.75, 7.23, 53.55).unwrap();
+    assert_eq!(bbox.to_string(), "3.31,50.75,7.23,53.55");
+    assert!(matches!(
+        BBox::new(7.23, 50.75, 3.31, 53.55),
+        Err(CKANError::GeoJsonError(_))
+    ));
+    assert!(BBox::new(3.31, 50.75, 7.23, 95.0).is_err());
+    spatial::validate(&bbox.to_geometry()).unwrap();
+
+    spatial::parse(r#"{"type": "Point", "coordinates": [4.9, 52.37]}"#).unwrap();
+    assert!(spatial::parse(r#"{"type": "Point", "coordinates": [200, 52.37]}"#).is_err());
+    assert!(
+        spatial::parse(r#"{"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 1]]]}"#)
+            .is_err()
+    );
+    assert!(spatial::parse(r#"{"type": "Circle"}"#).is_err());
+}
+
+#[tokio::test]
+async fn search_by_bbox_and_set_the_spatial_extra() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let url = common::serve(move |action, body| {
+        sink.lock().unwrap().push(body.clone());
+        match action {
+            "package_show" => json!({
+                "success": true,
+                "result": { "id": "d1", "extras": [{ "key": "spatial", "value": "old" }, { "key": "a", "value": "b" }] },
+            }),
+            "package_search" => json!({ "success": true, "result": { "count": 0, "results": [] } }),
+            _ => json!({ "success": true, "result": body }),
+        }
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    ckan.package_search()
+        .ext_bbox(BBox::new(-1.0, -2.0, 3.0, 4.0).unwrap())
+        .ext_prev_extent(BBox::new(-2.0, -3.0, 4.0, 5.0).unwrap())
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(
+        sent.lock().unwrap()[0],
+        json!({ "extras": { "ext_bbox": "-1,-2,3,4", "ext_prev_extent": "-2,-3,4,5" } }),
+        "ckanext-spatial reads ext_* parameters from the extras of the search"
+    );
+    ckan.send(
+        &PackageSearch::builder()
+            .ext_bbox(BBox::new(-1.0, -2.0, 3.0, 4.0).unwrap())
+            .build(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        sent.lock().unwrap().remove(1),
+        json!({ "extras": { "ext_bbox": "-1,-2,3,4" } })
+    );
+
+    ckan.package_set_spatial()
+        .id("d1")
+        .geometry(BBox::new(0.0, 0.0, 1.0, 1.0).unwrap())
+        .call()
+        .await
+        .unwrap();
+    let patched = sent.lock().unwrap()[2].clone();
+    assert_eq!(patched["extras"][0], json!({ "key": "a", "value": "b" }));
+    let spatial = patched["extras"][1]["value"].as_str().unwrap();
+    assert_eq!(
+        spatial::parse(spatial).unwrap(),
+        BBox::new(0.0, 0.0, 1.0, 1.0).unwrap().into()
+    );
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use ckanaction::{
+    CKAN,
+    sql::{Order, Query, avg, col, count_all},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn quote_identifiers_and_escape_values() {
+    let query = Query::from("0b7f5d5e")
+        .select([
+            col("station"),
+            avg(col("value")).alias("average"),
+            count_all(),
+        ])
+        .filter(col("year").ge(2000).and(!col("flag").is_null()))
+        .filter(col("station").ne("it's'; DROP TABLE \"x\"; --"))
+        .filter(col("code").is_in(["a", "b"]))
+        .group_by([col("station")])
+        .order_by(col("average"), Order::Desc)
+        .limit(10)
+        .offset(20);
+    assert_eq!(
+        query.to_sql(),
+        "SELECT \"station\", AVG(\"value\") AS \"average\", COUNT(*) FROM \"0b7f5d5e\" \
+         WHERE ((\"year\" >= 2000) AND (NOT (\"flag\" IS NULL))) \
+         AND (\"station\" <> 'it''s''; DROP TABLE \"x\"; --') \
+         AND (\"code\" IN ('a', 'b')) \
+         GROUP BY \"station\" ORDER BY \"average\" DESC LIMIT 10 OFFSET 20"
+    );
+    assert_eq!(
+        Query::from("a\"b")
+            .filter(col("x").eq(1.5).or(col("y").eq(Option::<i64>::None)))
+            .filter(col("z").is_in(Vec::<i64>::new()))
+            .to_sql(),
+        "SELECT * FROM \"a\"\"b\" WHERE ((\"x\" = 1.5) OR (\"y\" IS NULL)) AND (FALSE)"
+    );
+}
+
+#[tokio::test]
+async fn run_queries_as_typed_rows() {
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Station {
+        station: String,
+        average: f64,
+    }
+
+    let sent = Arc::new(Mutex::new(None));
+    let sink = sent.clone();
+    let url = common::serve(move |_, body| {
+        *sink.lock().unwrap() = Some(body);
+        json!({ "success": true, "result": { "records": [{ "station": "north", "average": 2.5 }] } })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let query = Query::from("r1")
+        .select([col("station"), avg(col("value")).alias("average")])
+        .group_by([col("station")]);
+    let stations: Vec<Station> = ckan.datastore_query(&query).await.unwrap();
+    assert_eq!(
+        stations,
+        [Station {
+            station: "north".to_string(),
+            average: 2.5
+        }]
+    );
+    assert_eq!(
+        sent.lock().unwrap().take().unwrap(),
+        json!({ "sql": "SELECT \"station\", AVG(\"value\") AS \"average\" FROM \"r1\" GROUP BY \"station\"" })
+    );
+}
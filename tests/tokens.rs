@@ -0,0 +1,112 @@
+mod common;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use ckanaction::{CKAN, CKANError, credentials::FileToken, tokens::Claims};
+use serde_json::{Value, json};
+use std::{sync::Arc, time::Duration};
+
+fn jwt(claims: Value) -> String {
+    let header = URL_SAFE_NO_PAD.encode(r#"{"alg":"HS256","typ":"JWT"}"#);
+    let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+    format!("{header}.{payload}.c2lnbmF0dXJl")
+}
+
+#[tokio::test]
+async fn rotate_the_token_of_a_live_client() {
+    let old = jwt(json!({ "jti": "old", "iat": 1_700_000_000 }));
+    let new = jwt(json!({ "jti": "new", "iat": 1_800_000_000, "exp": 1_802_592_000 }));
+    let created = new.clone();
+    let url = common::serve(move |action, body| match action {
+        "api_token_create" => {
+            assert_eq!(
+                body,
+                json!({ "user": "harvester", "name": "nightly", "expires_in": 2_592_000, "unit": 1 })
+            );
+            json!({ "success": true, "result": { "token": created } })
+        }
+        "api_token_revoke" => {
+            assert_eq!(body["jti"], "old");
+            json!({ "success": true, "result": null })
+        }
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).token(old).build();
+    let rotation = ckan
+        .rotate_token()
+        .user("harvester")
+        .name("nightly")
+        .expires_in(Duration::from_secs(30 * 24 * 60 * 60))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(rotation.claims.exp, Some(1_802_592_000));
+    assert_eq!(ckan.token().await.unwrap(), Some(new));
+    ckan.revoke_token_after()
+        .jti(rotation.previous.unwrap().jti)
+        .grace_period(Duration::from_millis(1))
+        .call()
+        .await
+        .unwrap();
+
+    let legacy = CKAN::builder()
+        .url(&url)
+        .token("0c5e8e1d-5a2f-4e55-9d4a-3f1b2c7d8e9f")
+        .build();
+    let rotation = legacy
+        .rotate_token()
+        .user("harvester")
+        .name("nightly")
+        .expires_in(Duration::from_secs(30 * 24 * 60 * 60))
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(rotation.previous, None, "legacy API keys are not JWTs");
+
+    let from_file = CKAN::builder()
+        .url(&url)
+        .credentials(Arc::new(FileToken::new("/run/secrets/ckan-token")))
+        .build();
+    let error = from_file
+        .rotate_token()
+        .user("harvester")
+        .name("nightly")
+        .call()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CKANError::Unsupported(_)), "{error:?}");
+    assert!(matches!(
+        Claims::decode("not-a-jwt"),
+        Err(CKANError::InvalidToken(_))
+    ));
+}
+
+#[tokio::test]
+async fn list_tokens_that_expire_soon_or_were_never_used() {
+    let url = common::serve(|action, _| match action {
+        "api_token_list" => json!({
+            "success": true,
+            "result": [
+                {
+                    "id": "unused", "name": "unused", "user_id": "u",
+                    "created_at": "2026-01-01T00:00:00", "last_access": null,
+                },
+                {
+                    "id": "expiring", "name": "expiring", "user_id": "u",
+                    "created_at": "2026-01-01T00:00:00", "last_access": "2026-02-01T00:00:00",
+                    "plugin_extras": { "expire_api_token": { "exp": "2000-01-01 00:00:00+00:00" } },
+                },
+                {
+                    "id": "fine", "name": "fine", "user_id": "u",
+                    "created_at": "2026-01-01T00:00:00", "last_access": "2026-02-01T00:00:00",
+                    "plugin_extras": { "expire_api_token": { "exp": "9999-01-01 00:00:00" } },
+                },
+            ],
+        }),
+        _ => json!({ "success": false, "error": { "__type": "Not Found Error" } }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let stale = ckan.stale_tokens().user_id("u").call().await.unwrap();
+    let ids: Vec<&str> = stale.iter().map(|token| token.id.as_str()).collect();
+    assert_eq!(ids, ["unused", "expiring"]);
+    assert_eq!(stale[1].expires(), Some(946_684_800));
+}
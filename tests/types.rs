@@ -0,0 +1,48 @@
+use ckanaction::{
+    CKAN,
+    types::{OrgId, Package},
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn pass_typed_ids_back_to_builders() {
+    let package: Package = serde_json::from_value(json!({
+        "id": "b3c5c6f1",
+        "name": "air-quality",
+        "owner_org": "7e1f",
+        "organization": { "id": "7e1f", "name": "city-of-example" },
+        "groups": [{ "id": "9a2b", "name": "environment" }],
+        "resources": [{ "id": "r1", "package_id": "b3c5c6f1" }],
+    }))
+    .unwrap();
+    assert_eq!(package.id, "b3c5c6f1");
+    assert_eq!(package.resources[0].package_id.as_ref(), Some(&package.id));
+    assert_eq!(
+        package
+            .organization
+            .as_ref()
+            .map(|organization| &organization.id),
+        package.owner_org.as_ref()
+    );
+    let ckan = CKAN::builder()
+        .url("http://127.0.0.1:1")
+        .dry_run(true)
+        .build();
+    ckan.resource_delete()
+        .id(&package.resources[0].id)
+        .call()
+        .await
+        .unwrap();
+    ckan.package_owner_org_update()
+        .id(package.id)
+        .organization_id(package.owner_org.unwrap())
+        .call()
+        .await
+        .unwrap();
+    let group: &OrgId = &package.groups[0].id;
+    ckan.group_delete().id(group).call().await.unwrap();
+    let journal = ckan.dry_run_journal();
+    assert_eq!(journal[0].body, Some(json!({ "id": "r1" })));
+    assert_eq!(journal[1].body.as_ref().unwrap()["organization_id"], "7e1f");
+    assert_eq!(journal[2].body.as_ref().unwrap()["id"], "9a2b");
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    version::{Version, required_plugin},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn parse_ckan_versions() {
+    assert_eq!(Version::parse("2.10.4"), Some(Version::new(2, 10, 4)));
+    assert_eq!(Version::parse("2.11"), Some(Version::new(2, 11, 0)));
+    assert_eq!(Version::parse("2.12.0a"), Some(Version::new(2, 12, 0)));
+    assert_eq!(Version::parse("unknown"), None);
+    assert!(Version::new(2, 9, 11) < Version::new(2, 10, 0));
+    assert_eq!(Version::new(2, 10, 1).to_string(), "2.10.1");
+}
+
+#[tokio::test]
+async fn gate_actions_on_the_server_version() {
+    let ckan = CKAN::builder()
+        .url("http://127.0.0.1:1")
+        .dry_run(true)
+        .version(Version::new(2, 9, 0))
+        .build();
+    assert_eq!(ckan.server_version().await.unwrap(), Version::new(2, 9, 0));
+    let error = ckan.file_delete().id("f1").call().await.unwrap_err();
+    assert!(matches!(error, CKANError::Unsupported(_)), "{error:?}");
+    ckan.package_delete().id("d1").call().await.unwrap();
+
+    let ckan = CKAN::builder()
+        .url("http://127.0.0.1:1")
+        .dry_run(true)
+        .version(Version::new(2, 12, 0))
+        .build();
+    ckan.file_delete().id("f1").call().await.unwrap();
+    assert_eq!(ckan.dry_run_journal()[0].action, "file_delete");
+}
+
+#[test]
+fn map_activity_actions_to_the_activity_plugin() {
+    let action = "package_activity_list";
+    assert_eq!(required_plugin(action, Version::new(2, 9, 11)), None);
+    assert_eq!(
+        required_plugin(action, Version::new(2, 10, 0)),
+        Some("activity")
+    );
+    assert_eq!(
+        required_plugin("package_show", Version::new(2, 11, 0)),
+        None
+    );
+}
+
+#[tokio::test]
+async fn detect_the_version_once_and_not_in_dry_run() {
+    let requests = Arc::new(Mutex::new(vec![]));
+    let seen = requests.clone();
+    let url = common::serve(move |action, _| {
+        let mut requests = seen.lock().unwrap();
+        requests.push(action.to_string());
+        if requests.len() == 1 {
+            json!({ "success": false, "error": { "message": "Internal Server Error" } })
+        } else {
+            json!({ "success": true, "result": { "ckan_version": "2.11.1" } })
+        }
+    });
+    let ckan = CKAN::builder().url(&url).dry_run(true).build();
+    ckan.file_delete().id("f1").call().await.unwrap();
+    assert!(requests.lock().unwrap().is_empty());
+
+    assert!(ckan.server_version().await.is_err());
+    let version = ckan.server_version().await.unwrap();
+    assert_eq!(
+        version,
+        Version::new(2, 11, 1),
+        "a failed detection is retried"
+    );
+    assert_eq!(ckan.server_version().await.unwrap(), version);
+    assert_eq!(*requests.lock().unwrap(), ["status_show", "status_show"]);
+}
@@ -0,0 +1,173 @@
+mod common;
+
+use ckanaction::{
+    CKAN, CKANError,
+    views::{DatatablesView, ImageView, View, ViewConfig},
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn serialize_view_configs() {
+    let table = DatatablesView {
+        show_fields: Some(vec!["station".to_string()]),
+        ..Default::default()
+    };
+    assert_eq!(table.view_type().as_str(), "datatables_view");
+    assert_eq!(
+        table.to_config().unwrap(),
+        json!({ "show_fields": ["station"] })
+    );
+}
+
+#[tokio::test]
+async fn sync_the_views_of_a_resource() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let url = common::serve(move |action, body| {
+        sink.lock()
+            .unwrap()
+            .push((action.to_string(), body.clone()));
+        let result = match action {
+            "resource_view_list" => json!([
+                { "id": "v1", "view_type": "image_view", "title": "Chart", "description": "", "image_url": "https://example.org/old.png" },
+                { "id": "v2", "view_type": "datatables_view", "title": "Table", "description": "", "responsive": true },
+                { "id": "v3", "view_type": "text_view", "title": "Raw", "description": "" },
+            ]),
+            "resource_view_create" => json!({ "id": "v4" }),
+            _ => json!({}),
+        };
+        json!({ "success": true, "result": result })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let views = vec![
+        View::new(
+            "Table",
+            &DatatablesView {
+                responsive: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap(),
+        View::new(
+            "Chart",
+            &ImageView {
+                image_url: "https://example.org/new.png".to_string(),
+            },
+        )
+        .unwrap(),
+        View::new("Map", &ckanaction::views::GeoJsonView {}).unwrap(),
+    ];
+    let sync = ckan
+        .sync_resource_views()
+        .resource_id("r1")
+        .views(views)
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(sync.unchanged, ["v2"]);
+    assert_eq!(sync.updated, ["v1"]);
+    assert_eq!(sync.created, ["v4"]);
+    assert_eq!(sync.deleted, ["v3"]);
+
+    let sent = sent.lock().unwrap();
+    let update = sent
+        .iter()
+        .find(|(action, _)| action == "resource_view_update")
+        .unwrap();
+    assert_eq!(update.1["image_url"], "https://example.org/new.png");
+    let create = sent
+        .iter()
+        .find(|(action, _)| action == "resource_view_create")
+        .unwrap();
+    assert_eq!(create.1["view_type"], "geojson_view");
+    assert_eq!(
+        sent.last().unwrap(),
+        &(
+            "resource_view_reorder".to_string(),
+            json!({ "id": "r1", "order": ["v2", "v1", "v4"] })
+        )
+    );
+}
+
+#[tokio::test]
+async fn fail_on_views_without_an_id() {
+    let url = common::serve(|action, _| match action {
+        "resource_view_list" => json!({ "success": true, "result": [] }),
+        _ => json!({ "success": true, "result": {} }),
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let error = ckan
+        .sync_resource_views()
+        .resource_id("r1")
+        .views(vec![
+            View::new("Map", &ckanaction::views::GeoJsonView {}).unwrap(),
+        ])
+        .call()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, CKANError::SerdeJsonError(_)), "{error:?}");
+}
+
+#[tokio::test]
+async fn leave_matching_views_alone_and_clear_unset_options() {
+    let sent = Arc::new(Mutex::new(vec![]));
+    let sink = sent.clone();
+    let url = common::serve(move |action, body| {
+        sink.lock()
+            .unwrap()
+            .push((action.to_string(), body.clone()));
+        let result = match action {
+            "resource_view_list" => json!([
+                { "id": "v1", "view_type": "datatables_view", "title": "Table", "description": "", "responsive": true },
+                { "id": "v2", "view_type": "image_view", "title": "Chart", "description": "", "image_url": "https://example.org/a.png" },
+            ]),
+            _ => json!({}),
+        };
+        json!({ "success": true, "result": result })
+    });
+    let ckan = CKAN::builder().url(&url).build();
+    let chart = View::new(
+        "Chart",
+        &ImageView {
+            image_url: "https://example.org/a.png".to_string(),
+        },
+    )
+    .unwrap();
+    let table = |responsive| {
+        View::new(
+            "Table",
+            &DatatablesView {
+                responsive,
+                ..Default::default()
+            },
+        )
+        .unwrap()
+    };
+
+    let sync = ckan
+        .sync_resource_views()
+        .resource_id("r1")
+        .views(vec![table(Some(true)), chart.clone()])
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(sync.unchanged, ["v1", "v2"]);
+    let actions: Vec<String> = sent.lock().unwrap().drain(..).map(|(a, _)| a).collect();
+    assert_eq!(
+        actions,
+        ["resource_view_list"],
+        "a no-op sync changes nothing"
+    );
+
+    let sync = ckan
+        .sync_resource_views()
+        .resource_id("r1")
+        .views(vec![table(None), chart])
+        .call()
+        .await
+        .unwrap();
+    assert_eq!(sync.updated, ["v1"], "clearing an option is a change");
+    let actions: Vec<String> = sent.lock().unwrap().drain(..).map(|(a, _)| a).collect();
+    assert_eq!(actions, ["resource_view_list", "resource_view_update"]);
+}
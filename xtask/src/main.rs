@@ -0,0 +1,49 @@
+//! Maintenance tasks of ckanaction, run with `cargo xtask <task>`.
+//!
+//! - `openapi`: regenerate `src/endpoints.rs` and `src/actions.rs` from `docs/lib/openapi.yml`.
+//! - `openapi --check`: print the difference instead and fail if there is one.
+
+mod openapi;
+
+use std::{path::Path, process::ExitCode};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args[..] {
+        ["openapi"] => endpoints(false),
+        ["openapi", "--check"] => endpoints(true),
+        _ => {
+            eprintln!("usage: cargo xtask openapi [--check]");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn endpoints(check: bool) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let spec = std::fs::read_to_string(root.join(openapi::SPEC)).expect("the spec can be read");
+    let spec = serde_yaml::from_str(&spec).expect("the spec is valid YAML");
+    let mut status = ExitCode::SUCCESS;
+    for (file, generate) in [
+        (openapi::ENDPOINTS, openapi::generate as fn(&_) -> String),
+        (openapi::ACTIONS, openapi::generate_actions),
+    ] {
+        let generated =
+            openapi::rustfmt(&generate(&spec)).expect("rustfmt is needed to generate code");
+        let path = root.join(file);
+        let current = std::fs::read_to_string(&path).unwrap_or_default();
+        if current == generated {
+            continue;
+        }
+        if check {
+            eprint!("{}", openapi::diff(&current, &generated));
+            eprintln!("{file} does not match {}", openapi::SPEC);
+            status = ExitCode::FAILURE;
+            continue;
+        }
+        std::fs::write(&path, generated).expect("the generated file can be written");
+        println!("regenerated {file}");
+    }
+    status
+}
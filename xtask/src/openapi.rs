@@ -0,0 +1,469 @@
+//! Generates the endpoint layer in `src/endpoints.rs`, and the typed requests and responses in
+//! `src/actions.rs`, from the OpenAPI description of the action API in `docs/lib/openapi.yml`.
+//!
+//! Besides the standard fields, the generator reads:
+//! - `externalDocs.url` of an operation, used as the doc comment of its method.
+//! - `x-read-only: true` on an operation, listed in `READ_ACTIONS`, which dry-run mode sends
+//!   to the server instead of recording.
+//! - `x-rust-type` of a property, an enum from `ckanaction::params` (or a type at the given path
+//!   in the crate) accepted through `Into`.
+//! - `x-rust-feature` of a property, the crate feature the parameter is only available with.
+//! - `x-rust-extra: true` on a property, sent in the `extras` object of the body, where CKAN
+//!   passes `ext_*` search parameters on to plugins.
+//! - `x-rust-generic: true` on a property, generated as a type parameter bound by `Serialize`.
+//! - `minimum: 0` on an integer property, generated as `u32`.
+//! - `additionalProperties: true` on a request body, generated as a `custom_fields` object (or
+//!   the name in `x-rust-additional-properties`) merged into the body.
+//! - The `200` response schema of an operation, the type of its `result`: a type named by
+//!   `x-rust-type`, an array, or an object generated as a struct. Without one, it is a
+//!   `serde_json::Value`.
+//!
+//! This file is also included by `tests/openapi.rs`, which checks the committed files.
+
+use serde_yaml::Value;
+use std::{
+    collections::BTreeSet,
+    io::Write,
+    process::{Command, Stdio},
+};
+
+pub const SPEC: &str = "docs/lib/openapi.yml";
+pub const ENDPOINTS: &str = "src/endpoints.rs";
+pub const ACTIONS: &str = "src/actions.rs";
+
+struct Param {
+    name: String,
+    ident: String,
+    ty: String,
+    into: bool,
+    required: bool,
+    upload: bool,
+    /// Whether this is the object of additional properties merged into the body.
+    extend: bool,
+    /// Whether `ty` is a type parameter of the method.
+    generic: bool,
+    /// The crate feature the parameter needs.
+    feature: Option<String>,
+    /// Whether the parameter is sent in the `extras` object of the body.
+    extra: bool,
+}
+
+fn ident(name: &str) -> String {
+    match name {
+        "type" | "match" => format!("_{name}"),
+        name => name.replace('.', "_"),
+    }
+}
+
+/// `package_show` as `PackageShow`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| part[..1].to_uppercase() + &part[1..])
+        .collect()
+}
+
+fn request_schema(operation: &Value) -> Option<&Value> {
+    operation["requestBody"]["content"]
+        .as_mapping()
+        .and_then(|content| content.values().next())
+        .map(|content| &content["schema"])
+}
+
+fn rust_type(property: &Value) -> (String, bool) {
+    if let Some(ty) = property["x-rust-type"].as_str() {
+        if ty.contains("::") {
+            return (format!("crate::{ty}"), true);
+        }
+        return (ty.to_string(), true);
+    }
+    if property.get("oneOf").is_some() {
+        return ("serde_json::Value".to_string(), true);
+    }
+    if property["type"] == "array" && property["items"].get("x-rust-type").is_some() {
+        return (format!("Vec<{}>", rust_type(&property["items"]).0), false);
+    }
+    let ty = match property["type"].as_str().unwrap_or_default() {
+        "string" if property["format"] == "binary" => "PathBuf",
+        "integer" if property["minimum"] == 0 => "u32",
+        "integer" => "i32",
+        "number" => "f64",
+        "boolean" => "bool",
+        "array" if property["items"]["type"] == "string" => "Vec<String>",
+        "array" => "Vec<serde_json::Value>",
+        "object" => "serde_json::Value",
+        _ => "String",
+    };
+    (ty.to_string(), false)
+}
+
+fn params(schema: &Value) -> Vec<Param> {
+    let required: Vec<&str> = schema["required"]
+        .as_sequence()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let mut generics = 0;
+    let mut params: Vec<Param> = schema["properties"]
+        .as_mapping()
+        .into_iter()
+        .flatten()
+        .map(|(name, property)| {
+            let name = name.as_str().unwrap().to_string();
+            let ident = ident(&name);
+            let generic = property["x-rust-generic"] == true;
+            let (ty, into) = if generic {
+                generics += 1;
+                let ty = match generics {
+                    1 => "T".to_string(),
+                    n => format!("T{n}"),
+                };
+                (ty, false)
+            } else {
+                rust_type(property)
+            };
+            Param {
+                required: required.contains(&name.as_str()),
+                upload: ty == "PathBuf",
+                extend: false,
+                name,
+                ident,
+                ty,
+                into,
+                generic,
+                feature: property["x-rust-feature"].as_str().map(str::to_string),
+                extra: property["x-rust-extra"] == true,
+            }
+        })
+        .collect();
+    if schema["additionalProperties"] == true {
+        let ident = schema["x-rust-additional-properties"]
+            .as_str()
+            .unwrap_or("custom_fields");
+        params.push(Param {
+            name: ident.to_string(),
+            ident: ident.to_string(),
+            ty: "serde_json::Value".to_string(),
+            into: false,
+            required: false,
+            upload: false,
+            extend: true,
+            generic: false,
+            feature: None,
+            extra: false,
+        });
+    }
+    params
+}
+
+fn endpoint(code: &mut String, enums: &mut BTreeSet<String>, method: &str, operation: &Value) {
+    let name = operation["operationId"].as_str().unwrap();
+    let url = operation["externalDocs"]["url"]
+        .as_str()
+        .unwrap_or_else(|| panic!("{name} has no externalDocs.url"));
+    let params = request_schema(operation).map(params).unwrap_or_default();
+
+    code.push_str(&format!("    /// {url}\n"));
+    if method == "get" {
+        assert!(params.is_empty(), "{name} is a GET action with parameters");
+        code.push_str(&format!(
+            "    pub async fn {name}(&self) -> Result<serde_json::Value, CKANError> {{\n        let endpoint = self.url.clone() + \"/api/3/action/{name}\";\n        Self::get(self, endpoint).await\n    }}\n\n"
+        ));
+        return;
+    }
+
+    let mut generics = vec![];
+    let mut signature = String::new();
+    let mut body = vec![];
+    let mut upload = None;
+    for param in &params {
+        if param.generic {
+            generics.push(format!("{}: serde::Serialize", param.ty));
+        }
+        if let Some(feature) = &param.feature {
+            signature.push_str(&format!("#[cfg(feature = \"{feature}\")] "));
+        }
+        if param.into {
+            enums.extend(
+                (param.ty != "serde_json::Value" && !param.ty.contains("::"))
+                    .then(|| param.ty.clone()),
+            );
+            signature.push_str("#[builder(into)] ");
+        }
+        let ty = if param.required {
+            param.ty.clone()
+        } else {
+            format!("Option<{}>", param.ty)
+        };
+        signature.push_str(&format!("{}: {ty}, ", param.ident));
+        let ident = &param.ident;
+        let entry = if param.upload {
+            upload = Some(format!("; (upload {ident})"));
+            continue;
+        } else if param.extend {
+            format!("(extend {ident})")
+        } else if param.extra {
+            assert!(
+                !param.required,
+                "{name}: {ident} is an extra and can not be required"
+            );
+            format!("(extra {ident})")
+        } else if param.name != *ident && param.required {
+            format!("(json \"{}\", {ident})", param.name)
+        } else if param.name != *ident {
+            format!("(\"{}\", {ident})", param.name)
+        } else if param.required {
+            format!("(json {ident})")
+        } else {
+            ident.clone()
+        };
+        match &param.feature {
+            Some(feature) => {
+                assert!(
+                    !param.required,
+                    "{name}: {ident} needs a feature and can not be required"
+                );
+                body.push(format!("(cfg \"{feature}\", {entry})"));
+            }
+            None => body.push(entry),
+        }
+    }
+    let generics = if generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.join(", "))
+    };
+    let body = if body.is_empty() {
+        String::new()
+    } else {
+        format!(", {}", body.join(", "))
+    };
+    code.push_str(&format!(
+        "    #[builder(on(String, into))]\n    pub async fn {name}{generics}(&self, {signature}) -> Result<serde_json::Value, CKANError> {{\n        post!(&self, \"{name}\"{body}{})\n    }}\n\n",
+        upload.unwrap_or_default()
+    ));
+}
+
+/// The unformatted endpoint layer for `spec`.
+pub fn generate(spec: &Value) -> String {
+    let mut code = String::new();
+    let mut enums = BTreeSet::new();
+    for (_, path) in spec["paths"].as_mapping().unwrap() {
+        for (method, operation) in path.as_mapping().unwrap() {
+            endpoint(&mut code, &mut enums, method.as_str().unwrap(), operation);
+        }
+    }
+    let enums = enums.into_iter().collect::<Vec<_>>().join(", ");
+    format!(
+        "//! CKAN actions, generated from `{SPEC}` by `cargo xtask openapi`. Do not edit by hand.\n\n\
+         use crate::{{CKAN, CKANError, hashmap_to_json, opsert, params::{{{enums}}}}};\n\
+         use bon::bon;\n\
+         use serde_json::json;\n\
+         use std::{{collections::HashMap, path::PathBuf}};\n\n\
+         #[bon]\nimpl CKAN {{\n{}}}\n",
+        code.trim_end()
+    )
+}
+
+/// The type of the `result` of `operation`, writing the struct for an object schema to `code`.
+fn response_type(code: &mut String, name: &str, operation: &Value) -> String {
+    let schema = &operation["responses"]["200"]["content"]["application/json"]["schema"];
+    if schema.is_null() {
+        return "serde_json::Value".to_string();
+    }
+    if schema["type"] != "object" || schema.get("x-rust-type").is_some() {
+        return rust_type(schema).0;
+    }
+    let response = format!("{}Response", pascal_case(name));
+    let required: Vec<&str> = schema["required"]
+        .as_sequence()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let mut fields = String::new();
+    for (property_name, property) in schema["properties"].as_mapping().into_iter().flatten() {
+        let property_name = property_name.as_str().unwrap();
+        let ident = ident(property_name);
+        if ident != property_name {
+            fields.push_str(&format!("#[serde(rename = \"{property_name}\")] "));
+        }
+        let ty = rust_type(property).0;
+        if required.contains(&property_name) {
+            fields.push_str(&format!("pub {ident}: {ty},\n"));
+        } else {
+            fields.push_str(&format!("pub {ident}: Option<{ty}>,\n"));
+        }
+    }
+    code.push_str(&format!(
+        "/// The result of `{name}`.\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub struct {response} {{\n{fields}}}\n\n"
+    ));
+    response
+}
+
+fn action(code: &mut String, enums: &mut BTreeSet<String>, method: &str, operation: &Value) {
+    let name = operation["operationId"].as_str().unwrap();
+    let url = operation["externalDocs"]["url"].as_str().unwrap();
+    let params = request_schema(operation).map(params).unwrap_or_default();
+    let response = response_type(code, name, operation);
+    let request = pascal_case(name);
+    let mut items = vec![
+        format!("const NAME: &'static str = \"{name}\";"),
+        format!("type Response = {response};"),
+    ];
+    code.push_str(&format!("/// {url}\n"));
+    if params.is_empty() {
+        code.push_str(&format!(
+            "#[derive(Debug, Clone, Copy, Default, Serialize)]\npub struct {request};\n\n"
+        ));
+        if method == "get" {
+            items.push("const GET: bool = true;".to_string());
+        }
+    } else {
+        let mut fields = String::new();
+        let mut extras = vec![];
+        for param in &params {
+            if let Some(feature) = &param.feature {
+                fields.push_str(&format!("#[cfg(feature = \"{feature}\")] "));
+            }
+            let mut ty = param.ty.clone();
+            if param.generic {
+                ty = "serde_json::Value".to_string();
+            } else if param.into {
+                enums.extend(
+                    (param.ty != "serde_json::Value" && !param.ty.contains("::"))
+                        .then(|| param.ty.clone()),
+                );
+                fields.push_str("#[builder(into)] ");
+            }
+            if param.upload {
+                fields.push_str("#[serde(skip)] ");
+                items.push(if param.required {
+                    "fn upload(&self) -> Option<PathBuf> { Some(self.upload.clone()) }".to_string()
+                } else {
+                    "fn upload(&self) -> Option<PathBuf> { self.upload.clone() }".to_string()
+                });
+            } else if param.extend {
+                ty = "serde_json::Map<String, serde_json::Value>".to_string();
+                fields.push_str("#[serde(flatten)] ");
+            } else {
+                if param.name != param.ident {
+                    fields.push_str(&format!("#[serde(rename = \"{}\")] ", param.name));
+                }
+                if !param.required {
+                    fields.push_str("#[serde(skip_serializing_if = \"Option::is_none\")] ");
+                }
+            }
+            if param.extra {
+                extras.push(format!("\"{}\"", param.name));
+            }
+            if param.required {
+                fields.push_str(&format!("pub {}: {ty},\n", param.ident));
+            } else {
+                fields.push_str(&format!("pub {}: Option<{ty}>,\n", param.ident));
+            }
+        }
+        if !extras.is_empty() {
+            items.push(format!(
+                "const EXTRAS: &'static [&'static str] = &[{}];",
+                extras.join(", ")
+            ));
+        }
+        code.push_str(&format!(
+            "#[derive(Debug, Clone, Serialize, bon::Builder)]\n#[builder(on(String, into))]\npub struct {request} {{\n{fields}}}\n\n"
+        ));
+    }
+    code.push_str(&format!(
+        "impl Action for {request} {{\n{}\n}}\n\n",
+        items.join("\n")
+    ));
+}
+
+/// The unformatted typed requests and responses for `spec`.
+pub fn generate_actions(spec: &Value) -> String {
+    let mut code = String::new();
+    let mut enums = BTreeSet::new();
+    let mut reads = vec![];
+    for (_, path) in spec["paths"].as_mapping().unwrap() {
+        for (method, operation) in path.as_mapping().unwrap() {
+            action(&mut code, &mut enums, method.as_str().unwrap(), operation);
+            if operation["x-read-only"] == true {
+                reads.push(format!(
+                    "\"{}\"",
+                    operation["operationId"].as_str().unwrap()
+                ));
+            }
+        }
+    }
+    let enums = enums.into_iter().collect::<Vec<_>>().join(", ");
+    let reads = reads.join(", ");
+    format!(
+        "//! Typed requests and responses of the CKAN actions, generated from `{SPEC}`\n\
+         //! by `cargo xtask openapi`. Do not edit by hand. Send a request with\n\
+         //! [`CKAN::send`](crate::CKAN::send).\n\n\
+         use crate::{{Action, params::{{{enums}}}}};\n\
+         use serde::{{Deserialize, Serialize}};\n\
+         use std::path::PathBuf;\n\n\
+         /// The actions that only read data, marked with `x-read-only` in the spec.\n\
+         pub const READ_ACTIONS: &[&str] = &[{reads}];\n\n{}\n",
+        code.trim_end()
+    )
+}
+
+/// Format `code` with rustfmt, or `None` if rustfmt can not be run.
+pub fn rustfmt(code: &str) -> Option<String> {
+    let mut rustfmt = Command::new(std::env::var("RUSTFMT").unwrap_or("rustfmt".to_string()))
+        .args(["--edition", "2024"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .ok()?;
+    rustfmt
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(code.as_bytes())
+        .unwrap();
+    let output = rustfmt.wait_with_output().unwrap();
+    assert!(output.status.success(), "generated code does not parse");
+    Some(String::from_utf8(output.stdout).unwrap())
+}
+
+/// The lines that differ between `old` and `new`, prefixed with `-` and `+`, with the line
+/// numbers of `old` they are at.
+pub fn diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // Length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    let mut hunk = false;
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            (i, j, hunk) = (i + 1, j + 1, false);
+            continue;
+        }
+        if !hunk {
+            diff.push_str(&format!("@@ line {} @@\n", i + 1));
+            hunk = true;
+        }
+        if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            diff.push_str(&format!("-{}\n", old[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+{}\n", new[j]));
+            j += 1;
+        }
+    }
+    diff
+}